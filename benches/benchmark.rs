@@ -32,6 +32,27 @@ fn contains(c: &mut Criterion) {
             ipv6_network.contains(Ipv6Addr::new(127, 0, 0, 1, 0, 0, 0, 0));
         })
     });
+    c.bench_function("contains ipv4 default route", move |b| {
+        b.iter(|| Ipv4Network::DEFAULT_ROUTE.contains(Ipv4Addr::new(127, 0, 0, 1)))
+    });
+    c.bench_function("contains ipv6 default route", move |b| {
+        b.iter(|| Ipv6Network::DEFAULT_ROUTE.contains(Ipv6Addr::new(127, 0, 0, 1, 0, 0, 0, 0)))
+    });
+}
+
+fn contains_network(c: &mut Criterion) {
+    let ipv4_network = Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 0), 8).unwrap();
+    let ipv4_subnet = Ipv4Network::new(Ipv4Addr::new(127, 1, 0, 0), 16).unwrap();
+
+    c.bench_function("contains_network ipv4", move |b| {
+        b.iter(|| ipv4_network.contains_network(&ipv4_subnet))
+    });
+    c.bench_function("contains_network ipv4 naive", move |b| {
+        b.iter(|| {
+            ipv4_subnet.netmask() >= ipv4_network.netmask()
+                && ipv4_network.contains(ipv4_subnet.network_address())
+        })
+    });
 }
 
 fn collapse_addresses(c: &mut Criterion) {
@@ -84,5 +105,11 @@ fn collapse_addresses(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, parse, contains, collapse_addresses);
+criterion_group!(
+    benches,
+    parse,
+    contains,
+    contains_network,
+    collapse_addresses
+);
 criterion_main!(benches);
@@ -0,0 +1,67 @@
+use crate::Ipv4Network;
+
+/// Extension trait adding [`aggregate`] to iterators of `Ipv4Network`.
+///
+/// [`aggregate`]: #tymethod.aggregate
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use ip_network::{AggregateExt, Ipv4Network};
+///
+/// let networks = vec![
+///     Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 128), 25)?,
+///     Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 0), 25)?,
+/// ];
+///
+/// assert_eq!(
+///     networks.into_iter().aggregate(),
+///     vec![Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 0), 24)?]
+/// );
+/// # Ok::<(), ip_network::IpNetworkError>(())
+/// ```
+pub trait AggregateExt: Iterator<Item = Ipv4Network> + Sized {
+    /// Returns networks from iterator collapsed to the smallest possible list, same as
+    /// [`Ipv4Network::collapse_addresses`].
+    ///
+    /// [`Ipv4Network::collapse_addresses`]: struct.Ipv4Network.html#method.collapse_addresses
+    fn aggregate(self) -> Vec<Ipv4Network> {
+        let networks: Vec<Ipv4Network> = self.collect();
+        Ipv4Network::collapse_addresses(&networks)
+    }
+}
+
+impl<I: Iterator<Item = Ipv4Network>> AggregateExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+    use crate::{AggregateExt, Ipv4Network};
+
+    #[test]
+    fn aggregate_after_filter() {
+        let networks = vec![
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 1, 0), 24).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 24).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap(),
+        ];
+
+        let aggregated = networks
+            .into_iter()
+            .filter(|network| network.is_private())
+            .filter(|network| network.network_address().octets()[0] == 10)
+            .aggregate();
+
+        assert_eq!(
+            aggregated,
+            vec![Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 23).unwrap()]
+        );
+    }
+
+    #[test]
+    fn aggregate_empty() {
+        assert!(std::iter::empty::<Ipv4Network>().aggregate().is_empty());
+    }
+}
@@ -0,0 +1,225 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use crate::{IpNetwork, Ipv4Network, Ipv6Network};
+
+/// Types that can be inside network `N`, used by `contains` methods of network types, so they
+/// accept both IP addresses and networks.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use ip_network::Ipv4Network;
+///
+/// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16)?;
+/// assert!(ip_network.contains(Ipv4Addr::new(192, 168, 1, 1)));
+/// assert!(ip_network.contains(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?));
+/// # Ok::<(), ip_network::IpNetworkError>(())
+/// ```
+pub trait Contained<N> {
+    /// Returns [`true`] if `self` is inside given `network`.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    fn is_contained_in(&self, network: &N) -> bool;
+}
+
+impl<N, T: Contained<N> + ?Sized> Contained<N> for &T {
+    #[inline]
+    fn is_contained_in(&self, network: &N) -> bool {
+        (**self).is_contained_in(network)
+    }
+}
+
+impl Contained<Ipv4Network> for Ipv4Addr {
+    #[inline]
+    fn is_contained_in(&self, network: &Ipv4Network) -> bool {
+        network.contains_address(*self)
+    }
+}
+
+impl Contained<Ipv4Network> for Ipv4Network {
+    #[inline]
+    fn is_contained_in(&self, network: &Ipv4Network) -> bool {
+        network.contains_network(self)
+    }
+}
+
+impl Contained<Ipv6Network> for Ipv6Addr {
+    #[inline]
+    fn is_contained_in(&self, network: &Ipv6Network) -> bool {
+        network.contains_address(*self)
+    }
+}
+
+impl Contained<Ipv6Network> for Ipv6Network {
+    #[inline]
+    fn is_contained_in(&self, network: &Ipv6Network) -> bool {
+        network.contains_network(self)
+    }
+}
+
+impl Contained<IpNetwork> for Ipv4Addr {
+    #[inline]
+    fn is_contained_in(&self, network: &IpNetwork) -> bool {
+        match network {
+            IpNetwork::V4(network) => network.contains_address(*self),
+            IpNetwork::V6(_) => false,
+        }
+    }
+}
+
+impl Contained<IpNetwork> for Ipv6Addr {
+    #[inline]
+    fn is_contained_in(&self, network: &IpNetwork) -> bool {
+        match network {
+            IpNetwork::V4(_) => false,
+            IpNetwork::V6(network) => network.contains_address(*self),
+        }
+    }
+}
+
+impl Contained<IpNetwork> for IpAddr {
+    #[inline]
+    fn is_contained_in(&self, network: &IpNetwork) -> bool {
+        match self {
+            IpAddr::V4(ip) => ip.is_contained_in(network),
+            IpAddr::V6(ip) => ip.is_contained_in(network),
+        }
+    }
+}
+
+impl Contained<IpNetwork> for [u8; 4] {
+    #[inline]
+    fn is_contained_in(&self, network: &IpNetwork) -> bool {
+        IpAddr::from(*self).is_contained_in(network)
+    }
+}
+
+impl Contained<IpNetwork> for [u8; 16] {
+    #[inline]
+    fn is_contained_in(&self, network: &IpNetwork) -> bool {
+        IpAddr::from(*self).is_contained_in(network)
+    }
+}
+
+impl Contained<IpNetwork> for [u16; 8] {
+    #[inline]
+    fn is_contained_in(&self, network: &IpNetwork) -> bool {
+        IpAddr::from(*self).is_contained_in(network)
+    }
+}
+
+impl Contained<IpNetwork> for Ipv4Network {
+    #[inline]
+    fn is_contained_in(&self, network: &IpNetwork) -> bool {
+        match network {
+            IpNetwork::V4(network) => network.contains_network(self),
+            IpNetwork::V6(_) => false,
+        }
+    }
+}
+
+impl Contained<IpNetwork> for Ipv6Network {
+    #[inline]
+    fn is_contained_in(&self, network: &IpNetwork) -> bool {
+        match network {
+            IpNetwork::V4(_) => false,
+            IpNetwork::V6(network) => network.contains_network(self),
+        }
+    }
+}
+
+impl Contained<IpNetwork> for IpNetwork {
+    #[inline]
+    fn is_contained_in(&self, network: &IpNetwork) -> bool {
+        match self {
+            IpNetwork::V4(ip_network) => ip_network.is_contained_in(network),
+            IpNetwork::V6(ip_network) => ip_network.is_contained_in(network),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use crate::{IpNetwork, Ipv4Network, Ipv6Network};
+
+    #[test]
+    fn ipv4_network_contains_address() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+        assert!(ip_network.contains(Ipv4Addr::new(192, 168, 1, 1)));
+        assert!(!ip_network.contains(Ipv4Addr::new(192, 169, 0, 0)));
+    }
+
+    #[test]
+    fn ipv4_network_contains_network() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+        let subnet = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert!(ip_network.contains(subnet));
+        assert!([subnet, ip_network]
+            .iter()
+            .all(|network| ip_network.contains(network)));
+        assert!(ip_network.contains(ip_network));
+        assert!(!subnet.contains(ip_network));
+        assert!(!ip_network.contains(Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24).unwrap()));
+    }
+
+    #[test]
+    fn ipv6_network_contains() {
+        let ip_network =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+        let subnet = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 1, 0, 0, 0, 0, 0), 48).unwrap();
+        assert!(ip_network.contains(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)));
+        assert!(ip_network.contains(subnet));
+        assert!(!subnet.contains(ip_network));
+    }
+
+    #[test]
+    fn ip_network_contains_same_version() {
+        let ipv4_network = IpNetwork::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+        let ipv4_subnet = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert!(ipv4_network.contains(ipv4_subnet));
+        assert!(ipv4_network.contains(IpNetwork::V4(ipv4_subnet)));
+        assert!(!IpNetwork::V4(ipv4_subnet).contains(ipv4_network));
+
+        let ipv6_network =
+            IpNetwork::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+        let ipv6_subnet =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 1, 0, 0, 0, 0, 0), 48).unwrap();
+        assert!(ipv6_network.contains(ipv6_subnet));
+        assert!(ipv6_network.contains(IpNetwork::V6(ipv6_subnet)));
+    }
+
+    #[test]
+    fn ip_network_contains_different_version() {
+        let ipv4_network = IpNetwork::from(Ipv4Network::DEFAULT_ROUTE);
+        let ipv6_network = IpNetwork::from(Ipv6Network::DEFAULT_ROUTE);
+
+        assert!(!ipv4_network.contains(Ipv6Network::DEFAULT_ROUTE));
+        assert!(!ipv4_network.contains(ipv6_network));
+        assert!(!ipv4_network.contains(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)));
+        assert!(!ipv6_network.contains(Ipv4Network::DEFAULT_ROUTE));
+        assert!(!ipv6_network.contains(ipv4_network));
+        assert!(!ipv6_network.contains(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))));
+    }
+
+    #[test]
+    fn ip_network_contains_address() {
+        let ip_network = IpNetwork::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+        assert!(ip_network.contains(Ipv4Addr::new(192, 168, 1, 1)));
+        assert!(ip_network.contains(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1))));
+        assert!(!ip_network.contains(Ipv4Addr::new(192, 169, 1, 1)));
+    }
+
+    #[test]
+    fn ip_network_contains_octets() {
+        let ipv4_network = IpNetwork::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+        assert!(ipv4_network.contains([192, 168, 1, 1]));
+        assert!(!ipv4_network.contains([192, 169, 1, 1]));
+        assert!(!ipv4_network.contains([0u16; 8]));
+
+        let ipv6_network = IpNetwork::from(Ipv6Network::DEFAULT_ROUTE);
+        assert!(ipv6_network.contains([0u16; 8]));
+        assert!(ipv6_network.contains([0u8; 16]));
+        assert!(!ipv6_network.contains([192, 168, 1, 1]));
+    }
+}
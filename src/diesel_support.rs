@@ -10,29 +10,21 @@ use crate::postgres_common;
 impl FromSql<Cidr, Pg> for Ipv4Network {
     fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
         let bytes = not_none!(bytes);
-        postgres_common::from_sql_ipv4_network(bytes)
+        Ok(postgres_common::from_sql_ipv4_network(bytes)?)
     }
 }
 
 impl FromSql<Cidr, Pg> for Ipv6Network {
     fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
         let bytes = not_none!(bytes);
-        postgres_common::from_sql_ipv6_network(bytes)
+        Ok(postgres_common::from_sql_ipv6_network(bytes)?)
     }
 }
 
 impl FromSql<Cidr, Pg> for IpNetwork {
     fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
         let bytes = not_none!(bytes);
-        match bytes[0] {
-            postgres_common::IPV4_TYPE => Ok(IpNetwork::V4(
-                postgres_common::from_sql_ipv4_network(bytes)?,
-            )),
-            postgres_common::IPV6_TYPE => Ok(IpNetwork::V6(
-                postgres_common::from_sql_ipv6_network(bytes)?,
-            )),
-            _ => Err("CIDR is not IP version 4 or 6".into()),
-        }
+        Ok(postgres_common::from_sql_ip_network(bytes)?)
     }
 }
 
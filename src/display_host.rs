@@ -0,0 +1,100 @@
+use std::fmt;
+use crate::{IpNetwork, Ipv4Network, Ipv6Network};
+
+/// Helper struct for formatting network without netmask when network contains just one address
+/// (netmask is 32 for IPv4 or 128 for IPv6). Other networks are formatted in CIDR notation.
+///
+/// Returned by `display_host` method of network types, so network can be formatted without
+/// allocating new `String`.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use ip_network::Ipv4Network;
+///
+/// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32)?;
+/// assert_eq!(format!("{}", ip_network.display_host()), "192.168.1.1");
+/// # Ok::<(), ip_network::IpNetworkError>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayHost<'a, N> {
+    network: &'a N,
+}
+
+impl<'a, N> DisplayHost<'a, N> {
+    pub(crate) fn new(network: &'a N) -> Self {
+        Self { network }
+    }
+}
+
+impl fmt::Display for DisplayHost<'_, Ipv4Network> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if self.network.netmask() == Ipv4Network::LENGTH {
+            fmt::Display::fmt(&self.network.network_address(), fmt)
+        } else {
+            fmt::Display::fmt(self.network, fmt)
+        }
+    }
+}
+
+impl fmt::Display for DisplayHost<'_, Ipv6Network> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if self.network.netmask() == Ipv6Network::LENGTH {
+            fmt::Display::fmt(&self.network.network_address(), fmt)
+        } else {
+            fmt::Display::fmt(self.network, fmt)
+        }
+    }
+}
+
+impl fmt::Display for DisplayHost<'_, IpNetwork> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.network {
+            IpNetwork::V4(network) => fmt::Display::fmt(&network.display_host(), fmt),
+            IpNetwork::V6(network) => fmt::Display::fmt(&network.display_host(), fmt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use crate::{IpNetwork, Ipv4Network, Ipv6Network};
+
+    #[test]
+    fn ipv4_network() {
+        let host = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32).unwrap();
+        assert_eq!("192.168.1.1", host.display_host().to_string());
+
+        let network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!("192.168.1.0/24", network.display_host().to_string());
+    }
+
+    #[test]
+    fn ipv6_network() {
+        let host = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 128).unwrap();
+        assert_eq!("2001:db8::1", host.display_host().to_string());
+
+        let network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+        assert_eq!("2001:db8::/32", network.display_host().to_string());
+    }
+
+    #[test]
+    fn ip_network() {
+        let host = IpNetwork::new(Ipv4Addr::new(10, 0, 0, 1), 32).unwrap();
+        assert_eq!("10.0.0.1", host.display_host().to_string());
+
+        let network = IpNetwork::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+        assert_eq!("2001:db8::/32", network.display_host().to_string());
+    }
+
+    #[test]
+    fn padding() {
+        let host = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 1), 32).unwrap();
+        assert_eq!("10.0.0.1  ", format!("{:<10}", host.display_host()));
+
+        let network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        assert_eq!("  10.0.0.0/8", format!("{:>12}", network.display_host()));
+    }
+}
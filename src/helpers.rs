@@ -1,8 +1,46 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::{self, FromStr};
+use crate::IpNetworkParseError;
+
+/// Fixed capacity buffer implementing `fmt::Write`, used for formatting without allocation.
+/// Writing string that doesn't fit into remaining capacity fails without writing anything.
+pub struct FixedBuffer<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedBuffer<N> {
+    pub const fn new() -> Self {
+        Self {
+            data: [0; N],
+            len: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Buffer is filled only by whole `&str` values, so it always contains valid UTF-8
+        str::from_utf8(&self.data[..self.len]).unwrap()
+    }
+}
+
+impl<const N: usize> fmt::Write for FixedBuffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > N {
+            return Err(fmt::Error);
+        }
+        self.data[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
 pub fn bit_length(number: u32) -> u8 {
     32 - number.leading_zeros() as u8
 }
 
-pub fn bite_mask(mask: u8) -> u32 {
+pub const fn bite_mask(mask: u8) -> u32 {
     debug_assert!(mask <= 32);
     match mask {
         0 => 0,
@@ -10,7 +48,7 @@ pub fn bite_mask(mask: u8) -> u32 {
     }
 }
 
-pub fn bite_mask_u128(mask: u8) -> u128 {
+pub const fn bite_mask_u128(mask: u8) -> u128 {
     debug_assert!(mask <= 128);
     match mask {
         0 => 0,
@@ -19,10 +57,7 @@ pub fn bite_mask_u128(mask: u8) -> u128 {
 }
 
 pub fn split_ip_netmask(input: &str) -> Option<(&str, &str)> {
-    let delimiter = match input.find('/') {
-        Some(pos) => pos,
-        None => return None,
-    };
+    let delimiter = input.find('/')?;
     let (ip, mask) = input.split_at(delimiter);
     let mask = &mask[1..];
 
@@ -33,9 +68,182 @@ pub fn split_ip_netmask(input: &str) -> Option<(&str, &str)> {
     }
 }
 
+/// Same as `split_ip_netmask`, but accepts also input without netmask, in that case returned
+/// netmask is `None`.
+pub fn split_ip_optional_netmask(input: &str) -> Option<(&str, Option<&str>)> {
+    if input.contains('/') {
+        split_ip_netmask(input).map(|(ip, netmask)| (ip, Some(netmask)))
+    } else if input.is_empty() {
+        None
+    } else {
+        Some((input, None))
+    }
+}
+
+/// Parses netmask part of CIDR string. `offset` is byte position of `netmask` in original input.
+pub fn parse_netmask(netmask: &str, offset: usize) -> Result<u8, IpNetworkParseError> {
+    u8::from_str(netmask).map_err(|_| IpNetworkParseError::InvalidNetmaskFormat {
+        span: offset..offset + netmask.len(),
+    })
+}
+
+/// Computes 64-bit FNV-1a hash of given bytes.
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Parses netmask part of IPv4 CIDR string, that can be prefix length or dotted network mask
+/// (like 255.255.255.0). `offset` is byte position of `netmask` in original input.
+pub fn parse_ipv4_netmask(netmask: &str, offset: usize) -> Result<u8, IpNetworkParseError> {
+    parse_netmask(netmask, offset).or_else(|error| {
+        Ipv4Addr::from_str(netmask)
+            .ok()
+            .and_then(netmask_from_ipv4)
+            .ok_or(error)
+    })
+}
+
+/// Converts dotted network mask (like 255.255.255.0) to prefix length. Returns `None` when mask
+/// bits are not contiguous.
+pub fn netmask_from_ipv4(mask: Ipv4Addr) -> Option<u8> {
+    let host_bits = !u32::from(mask);
+    if host_bits & host_bits.wrapping_add(1) != 0 {
+        return None;
+    }
+    Some(u32::from(mask).leading_ones() as u8)
+}
+
+/// Classifies why `ip` is not valid IPv4 address. Returns `AddrOctetOutOfRange` when `ip` has
+/// four dot separated decimal octets and one of them is bigger than 255, otherwise
+/// `AddrParseError`. Span of the octet is relative to the start of `ip`.
+pub fn ipv4_addr_parse_error(ip: &str) -> IpNetworkParseError {
+    let mut parts = 0;
+    let mut out_of_range = None;
+    let mut start = 0;
+
+    for part in ip.split('.') {
+        if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return IpNetworkParseError::AddrParseError;
+        }
+        if out_of_range.is_none() && u8::from_str(part).is_err() {
+            out_of_range = Some(start..start + part.len());
+        }
+        parts += 1;
+        start += part.len() + 1;
+    }
+
+    match out_of_range {
+        Some(span) if parts == 4 => IpNetworkParseError::AddrOctetOutOfRange { span },
+        _ => IpNetworkParseError::AddrParseError,
+    }
+}
+
+/// Returns `true` if both IPv4 addresses are the same under given prefix (they belong to the same
+/// network with `prefix` netmask).
+///
+/// # Panics
+///
+/// This function panics when prefix is bigger than 32.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+///
+/// assert!(ip_network::same_prefix(Ipv4Addr::new(192, 168, 1, 1), Ipv4Addr::new(192, 168, 1, 200), 24));
+/// assert!(!ip_network::same_prefix(Ipv4Addr::new(192, 168, 1, 1), Ipv4Addr::new(192, 168, 2, 1), 24));
+/// ```
+pub fn same_prefix(a: Ipv4Addr, b: Ipv4Addr, prefix: u8) -> bool {
+    assert!(prefix <= 32, "invalid prefix");
+    let mask = bite_mask(prefix);
+    u32::from(a) & mask == u32::from(b) & mask
+}
+
+/// Returns `true` if both IPv6 addresses are the same under given prefix (they belong to the same
+/// network with `prefix` netmask).
+///
+/// # Panics
+///
+/// This function panics when prefix is bigger than 128.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv6Addr;
+///
+/// assert!(ip_network::same_prefix_ipv6(
+///     Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+///     Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 1, 0, 0, 1),
+///     64,
+/// ));
+/// ```
+pub fn same_prefix_ipv6(a: Ipv6Addr, b: Ipv6Addr, prefix: u8) -> bool {
+    assert!(prefix <= 128, "invalid prefix");
+    let mask = bite_mask_u128(prefix);
+    u128::from(a) & mask == u128::from(b) & mask
+}
+
+/// Checks that `new` accepts network address only when host bits are not set and that
+/// `new_truncate` returns the same network in that case. Tested addresses are addresses with single
+/// bit set, boundary values and pseudo random addresses (xorshift).
+#[cfg(test)]
+macro_rules! test_new_accepts_only_masked_addresses {
+    ($network:ident, $addr:ident, $int:ident) => {
+        let mut addresses = (0..$network::LENGTH)
+            .map(|bit| 1 << bit)
+            .collect::<Vec<$int>>();
+        addresses.extend_from_slice(&[0, $int::MAX, $int::MAX << 1, $int::MAX >> 1]);
+        let mut state = 0x2001_0db8_85a3_0000_0000_8a2e_0370_7334u128;
+        for _ in 0..256 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let address = state as $int;
+            addresses.push(address);
+            addresses.push(address & ($int::MAX << (state % u128::from($network::LENGTH))));
+        }
+
+        for netmask in 0..=$network::LENGTH {
+            let host_mask = $int::MAX.checked_shr(u32::from(netmask)).unwrap_or(0);
+
+            for &address in &addresses {
+                let ip = $addr::from(address);
+                let truncated = $network::new_truncate(ip, netmask).unwrap();
+                let expected = address & host_mask == 0;
+
+                assert_eq!(expected, truncated.network_address() == ip);
+                match $network::new(ip, netmask) {
+                    Ok(network) => {
+                        assert!(expected, "{}/{} should be rejected", ip, netmask);
+                        assert_eq!(truncated, network);
+                    }
+                    Err(err) => {
+                        assert!(!expected, "{}/{} should be accepted", ip, netmask);
+                        assert_eq!(crate::IpNetworkError::HostBitsSet, err);
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use test_new_accepts_only_masked_addresses;
+
 #[cfg(test)]
 mod tests {
-    use super::{bite_mask, bite_mask_u128, split_ip_netmask};
+    use super::{
+        bite_mask, bite_mask_u128, fnv1a_64, ipv4_addr_parse_error, netmask_from_ipv4,
+        parse_ipv4_netmask, parse_netmask, same_prefix, same_prefix_ipv6, split_ip_netmask,
+        split_ip_optional_netmask,
+    };
+    use crate::IpNetworkParseError;
+    use std::net::{Ipv4Addr, Ipv6Addr};
 
     #[test]
     fn get_bite_mask_32() {
@@ -81,4 +289,133 @@ mod tests {
         let a = split_ip_netmask("192.168.1.1/");
         assert!(a.is_none());
     }
+
+    #[test]
+    fn split_ip_optional_netmask_valid() {
+        assert_eq!(
+            Some(("192.168.1.1", Some("24"))),
+            split_ip_optional_netmask("192.168.1.1/24")
+        );
+        assert_eq!(
+            Some(("192.168.1.1", None)),
+            split_ip_optional_netmask("192.168.1.1")
+        );
+    }
+
+    #[test]
+    fn split_ip_optional_netmask_invalid() {
+        assert!(split_ip_optional_netmask("").is_none());
+        assert!(split_ip_optional_netmask("192.168.1.1/").is_none());
+        assert!(split_ip_optional_netmask("/24").is_none());
+    }
+
+    #[test]
+    fn parse_netmask_valid() {
+        assert_eq!(Ok(24), parse_netmask("24", 12));
+    }
+
+    #[test]
+    fn parse_netmask_invalid() {
+        assert_eq!(
+            Err(IpNetworkParseError::InvalidNetmaskFormat { span: 12..15 }),
+            parse_netmask("abc", 12)
+        );
+    }
+
+    #[test]
+    fn fnv1a_64_known_values() {
+        assert_eq!(0xcbf2_9ce4_8422_2325, fnv1a_64(&[]));
+        assert_eq!(0xaf63_dc4c_8601_ec8c, fnv1a_64(b"a"));
+    }
+
+    #[test]
+    fn parse_ipv4_netmask_dotted() {
+        assert_eq!(Ok(24), parse_ipv4_netmask("24", 0));
+        assert_eq!(Ok(24), parse_ipv4_netmask("255.255.255.0", 0));
+        assert_eq!(
+            Err(IpNetworkParseError::InvalidNetmaskFormat { span: 5..16 }),
+            parse_ipv4_netmask("255.0.255.0", 5)
+        );
+    }
+
+    #[test]
+    fn netmask_from_ipv4_valid() {
+        assert_eq!(Some(0), netmask_from_ipv4(Ipv4Addr::new(0, 0, 0, 0)));
+        assert_eq!(Some(24), netmask_from_ipv4(Ipv4Addr::new(255, 255, 255, 0)));
+        assert_eq!(
+            Some(25),
+            netmask_from_ipv4(Ipv4Addr::new(255, 255, 255, 128))
+        );
+        assert_eq!(
+            Some(32),
+            netmask_from_ipv4(Ipv4Addr::new(255, 255, 255, 255))
+        );
+    }
+
+    #[test]
+    fn netmask_from_ipv4_not_contiguous() {
+        assert_eq!(None, netmask_from_ipv4(Ipv4Addr::new(255, 255, 0, 255)));
+        assert_eq!(None, netmask_from_ipv4(Ipv4Addr::new(0, 0, 0, 255)));
+        assert_eq!(None, netmask_from_ipv4(Ipv4Addr::new(255, 0, 255, 0)));
+    }
+
+    #[test]
+    fn ipv4_addr_parse_error_out_of_range() {
+        assert_eq!(
+            ipv4_addr_parse_error("1.2.300.4"),
+            IpNetworkParseError::AddrOctetOutOfRange { span: 4..7 }
+        );
+        assert_eq!(
+            ipv4_addr_parse_error("1.2.3.4000"),
+            IpNetworkParseError::AddrOctetOutOfRange { span: 6..10 }
+        );
+    }
+
+    #[test]
+    fn ipv4_addr_parse_error_bad_format() {
+        assert_eq!(
+            ipv4_addr_parse_error("1.2.3"),
+            IpNetworkParseError::AddrParseError
+        );
+        assert_eq!(
+            ipv4_addr_parse_error("1.2.300"),
+            IpNetworkParseError::AddrParseError
+        );
+        assert_eq!(
+            ipv4_addr_parse_error("1.2.3.a"),
+            IpNetworkParseError::AddrParseError
+        );
+        assert_eq!(
+            ipv4_addr_parse_error("1..3.4"),
+            IpNetworkParseError::AddrParseError
+        );
+    }
+
+    #[test]
+    fn same_prefix_ipv4() {
+        let a = Ipv4Addr::new(10, 0, 0, 1);
+        let b = Ipv4Addr::new(10, 0, 0, 200);
+        assert!(same_prefix(a, b, 24));
+        assert!(!same_prefix(a, b, 25));
+        assert!(same_prefix(a, b, 0));
+        assert!(!same_prefix(a, b, 32));
+        assert!(same_prefix(a, a, 32));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))] // currently, miri doesnt support should_panic tests
+    fn same_prefix_ipv4_invalid_prefix() {
+        same_prefix(Ipv4Addr::LOCALHOST, Ipv4Addr::LOCALHOST, 33);
+    }
+
+    #[test]
+    fn same_prefix_ipv6_addresses() {
+        let a = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1);
+        let b = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0xc8);
+        assert!(same_prefix_ipv6(a, b, 120));
+        assert!(!same_prefix_ipv6(a, b, 121));
+        assert!(same_prefix_ipv6(a, b, 0));
+        assert!(!same_prefix_ipv6(a, b, 128));
+    }
 }
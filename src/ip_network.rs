@@ -1,10 +1,12 @@
 use std::cmp::Ordering;
+use std::convert::TryInto;
 use std::fmt;
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
-use crate::{IpNetworkError, IpNetworkParseError};
+use crate::{Contained, DisplayHost, IpNetworkError, IpNetworkParseError};
 use crate::helpers;
-use crate::{Ipv4Network, Ipv6Network};
+use crate::postgres_common;
+use crate::{Ipv4Network, Ipv6MulticastScope, Ipv6Network};
 
 /// Holds IPv4 or IPv6 network.
 #[derive(Clone, Copy, Eq, PartialEq, Debug, Hash, PartialOrd, Ord)]
@@ -106,6 +108,53 @@ impl IpNetwork {
         }
     }
 
+    /// Compares networks by specificity, more specific networks (with longer prefix) are ordered
+    /// first. This is order used for route selection and differs from `Ord` implementation.
+    ///
+    /// Prefix lengths of different IP versions are not comparable, so IPv4 networks are ordered
+    /// before IPv6 networks. Networks of the same version and with the same prefix length are
+    /// ordered by network address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip_network::IpNetwork;
+    ///
+    /// let mut networks: Vec<IpNetwork> = vec![
+    ///     "0.0.0.0/0".parse().unwrap(),
+    ///     "192.168.1.0/24".parse().unwrap(),
+    ///     "192.168.1.1/32".parse().unwrap(),
+    /// ];
+    /// networks.sort_by(IpNetwork::cmp_specificity);
+    /// assert_eq!(networks[0], "192.168.1.1/32".parse::<IpNetwork>().unwrap());
+    /// assert_eq!(networks[2], "0.0.0.0/0".parse::<IpNetwork>().unwrap());
+    /// ```
+    pub fn cmp_specificity(&self, other: &IpNetwork) -> Ordering {
+        self.is_ipv6()
+            .cmp(&other.is_ipv6())
+            .then_with(|| other.netmask().cmp(&self.netmask()))
+            .then_with(|| self.cmp(other))
+    }
+
+    /// Returns object implementing `Display` that formats network without netmask when network
+    /// contains just one address (netmask is 32 for IPv4 or 128 for IPv6), otherwise in CIDR
+    /// notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::IpNetwork;
+    ///
+    /// let ip_network = IpNetwork::new(Ipv4Addr::new(192, 168, 1, 1), 32)?;
+    /// assert_eq!(ip_network.display_host().to_string(), "192.168.1.1");
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub fn display_host(&self) -> DisplayHost<'_, Self> {
+        DisplayHost::new(self)
+    }
+
     /// Returns `true` if `IpNetwork` contains `Ipv4Network` struct.
     pub fn is_ipv4(&self) -> bool {
         match self {
@@ -119,26 +168,85 @@ impl IpNetwork {
         !self.is_ipv4()
     }
 
-    /// Returns `true` if `IpNetwork` contains `IpAddr`. For different network type
-    /// (for example IpNetwork is IPv6 and IpAddr is IPv4) always returns `false`.
+    /// Returns "parallel" network from the other IP version using fixed mapping, useful for
+    /// dual-stack tests.
+    ///
+    /// IPv4 network `a.b.c.d/n` is embedded into bits 32 to 63 of documentation prefix
+    /// `2001:db8::/32`, so it is converted to IPv6 network with netmask `n + 32`. IPv6 network is
+    /// converted back by taking bits 32 to 63 of its network address as IPv4 address with netmask
+    /// `n - 32` (limited to range 0 to 32). The conversion round trips for all IPv4 networks.
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
     /// use ip_network::IpNetwork;
     ///
+    /// let ip_network: IpNetwork = "10.0.0.0/8".parse()?;
+    /// let other = ip_network.to_other_family_deterministic();
+    /// assert_eq!(other, "2001:db8:a00::/40".parse::<IpNetwork>()?);
+    /// assert_eq!(other.to_other_family_deterministic(), ip_network);
+    /// # Ok::<(), ip_network::IpNetworkParseError>(())
+    /// ```
+    pub fn to_other_family_deterministic(&self) -> IpNetwork {
+        const DOCUMENTATION_PREFIX: u128 = 0x2001_0db8 << 96;
+
+        match self {
+            IpNetwork::V4(network) => {
+                let address =
+                    DOCUMENTATION_PREFIX | u128::from(u32::from(network.network_address())) << 64;
+                IpNetwork::V6(
+                    Ipv6Network::new(Ipv6Addr::from(address), network.netmask() + 32).unwrap(),
+                )
+            }
+            IpNetwork::V6(network) => {
+                let address = (u128::from(network.network_address()) >> 64) as u32;
+                let netmask = network
+                    .netmask()
+                    .saturating_sub(32)
+                    .min(Ipv4Network::LENGTH);
+                IpNetwork::V4(Ipv4Network::new_truncate(Ipv4Addr::from(address), netmask).unwrap())
+            }
+        }
+    }
+
+    /// Returns `true` if `IpNetwork` contains given IP address or network. Accepted types are
+    /// `IpAddr`, `Ipv4Addr`, `Ipv6Addr`, `IpNetwork`, `Ipv4Network`, `Ipv6Network` and address
+    /// octets or segments (`[u8; 4]`, `[u8; 16]` and `[u16; 8]`). For different IP version (for
+    /// example IpNetwork is IPv6 and IpAddr is IPv4) always returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    /// use ip_network::{IpNetwork, Ipv4Network};
+    ///
     /// let ip_network = IpNetwork::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
     /// assert!(ip_network.contains(Ipv4Addr::new(192, 168, 1, 25)));
     /// assert!(!ip_network.contains(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 1, 0, 0)));
+    /// assert!(ip_network.contains(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25)?));
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn contains<I: Into<IpAddr>>(&self, ip: I) -> bool {
-        match (self, ip.into()) {
-            (IpNetwork::V4(network), IpAddr::V4(ip)) => network.contains(ip),
-            (IpNetwork::V6(network), IpAddr::V6(ip)) => network.contains(ip),
-            _ => false,
-        }
+    #[inline]
+    pub fn contains<T: Contained<Self>>(&self, other: T) -> bool {
+        other.is_contained_in(self)
+    }
+
+    /// Parses given string as IP address and returns `true` if it is inside this network. Returns
+    /// error when string is not valid IPv4 or IPv6 address. For address of different IP version
+    /// returns `Ok(false)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip_network::IpNetwork;
+    ///
+    /// let ip_network: IpNetwork = "192.168.1.0/24".parse().unwrap();
+    /// assert_eq!(ip_network.contains_str("192.168.1.25"), Ok(true));
+    /// assert_eq!(ip_network.contains_str("::1"), Ok(false));
+    /// assert!(ip_network.contains_str("192.168.1").is_err());
+    /// ```
+    pub fn contains_str(&self, ip: &str) -> Result<bool, AddrParseError> {
+        IpAddr::from_str(ip).map(|ip| self.contains(ip))
     }
 
     /// Returns `true` if the network is default route, that contains all IP addresses.
@@ -149,6 +257,15 @@ impl IpNetwork {
         }
     }
 
+    /// Returns `true` if the network is default route (0.0.0.0/0 or ::/0), same as
+    /// [`is_default_route`].
+    ///
+    /// [`is_default_route`]: #method.is_default_route
+    #[inline]
+    pub fn is_default(&self) -> bool {
+        self.is_default_route()
+    }
+
     /// Returns `true` if the network is part of multicast network range.
     pub fn is_multicast(&self) -> bool {
         match self {
@@ -157,6 +274,25 @@ impl IpNetwork {
         }
     }
 
+    /// Returns multicast scope for IPv6 multicast network. For IPv4 networks always returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip_network::{IpNetwork, Ipv6MulticastScope};
+    ///
+    /// let ip_network: IpNetwork = "ff0e::/16".parse().unwrap();
+    /// assert_eq!(ip_network.multicast_scope(), Some(Ipv6MulticastScope::Global));
+    /// let ip_network: IpNetwork = "224.0.0.0/4".parse().unwrap();
+    /// assert_eq!(ip_network.multicast_scope(), None);
+    /// ```
+    pub fn multicast_scope(&self) -> Option<Ipv6MulticastScope> {
+        match self {
+            IpNetwork::V4(_) => None,
+            IpNetwork::V6(ip_network) => ip_network.multicast_scope(),
+        }
+    }
+
     /// Returns `true` if this is a part of network reserved for documentation.
     pub fn is_documentation(&self) -> bool {
         match self {
@@ -165,6 +301,24 @@ impl IpNetwork {
         }
     }
 
+    /// Returns `true` if this is unspecified address network, that is 0.0.0.0/32 for IPv4 and ::/128
+    /// for IPv6.
+    pub fn is_unspecified(&self) -> bool {
+        match self {
+            IpNetwork::V4(ip_network) => ip_network.is_unspecified(),
+            IpNetwork::V6(ip_network) => ip_network.is_unspecified(),
+        }
+    }
+
+    /// Returns `true` if this network is inside link local address range, that is 169.254.0.0/16
+    /// for IPv4 and fe80::/10 for IPv6.
+    pub fn is_link_local(&self) -> bool {
+        match self {
+            IpNetwork::V4(ip_network) => ip_network.is_link_local(),
+            IpNetwork::V6(ip_network) => ip_network.is_unicast_link_local(),
+        }
+    }
+
     /// Returns `true` if this network is inside loopback address range.
     pub fn is_loopback(&self) -> bool {
         match self {
@@ -181,7 +335,33 @@ impl IpNetwork {
         }
     }
 
-    /// Converts string in format IPv4 (X.X.X.X/Y) or IPv6 (X:X::X/Y) CIDR notation to `IpNetwork`.
+    /// Parses address and netmask part of string in CIDR notation. Netmask of IPv4 address can be
+    /// also in dotted form. When netmask is missing, netmask 32 for IPv4 or 128 for IPv6 address is
+    /// returned.
+    fn parse_address_netmask(s: &str) -> Result<(IpAddr, u8), IpNetworkParseError> {
+        let (ip, netmask) =
+            helpers::split_ip_optional_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
+
+        let network_address = IpAddr::from_str(ip).map_err(|_| {
+            if ip.contains(':') {
+                IpNetworkParseError::AddrParseError
+            } else {
+                helpers::ipv4_addr_parse_error(ip)
+            }
+        })?;
+        let netmask = match (netmask, network_address) {
+            (Some(netmask), IpAddr::V4(_)) => helpers::parse_ipv4_netmask(netmask, ip.len() + 1)?,
+            (Some(netmask), IpAddr::V6(_)) => helpers::parse_netmask(netmask, ip.len() + 1)?,
+            (None, IpAddr::V4(_)) => Ipv4Network::LENGTH,
+            (None, IpAddr::V6(_)) => Ipv6Network::LENGTH,
+        };
+
+        Ok((network_address, netmask))
+    }
+
+    /// Converts string in format IPv4 (X.X.X.X/Y) or IPv6 (X:X::X/Y) CIDR notation to `IpNetwork`,
+    /// but truncating host bits. Plain IP address without netmask is converted to network with
+    /// netmask 32 for IPv4 or 128 for IPv6.
     ///
     /// # Examples
     ///
@@ -193,19 +373,144 @@ impl IpNetwork {
     /// assert_eq!(ip_network, IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap()));
     /// ```
     pub fn from_str_truncate(s: &str) -> Result<Self, IpNetworkParseError> {
-        let (ip, netmask) =
-            helpers::split_ip_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
-
-        let network_address =
-            IpAddr::from_str(ip).map_err(|_| IpNetworkParseError::AddrParseError)?;
-        let netmask =
-            u8::from_str(netmask).map_err(|_| IpNetworkParseError::InvalidNetmaskFormat)?;
+        let (network_address, netmask) = Self::parse_address_netmask(s)?;
 
         IpNetwork::new_truncate(network_address, netmask)
             .map_err(IpNetworkParseError::IpNetworkError)
     }
 
+    /// Converts string in format IPv4 (X.X.X.X/Y) or IPv6 (X:X::X/Y) CIDR notation to `IpNetwork`,
+    /// but rejects IPv4 octets with leading zero (like `010.0.0.0/8`), that are interpreted as octal
+    /// numbers by some parsers. This also applies to IPv4 part of IPv6 address.
+    ///
+    /// Standard library parser rejects such octets too, this method makes this behaviour explicit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::{IpNetwork, IpNetworkParseError, Ipv4Network};
+    ///
+    /// let ip_network = IpNetwork::from_str_strict("10.0.0.0/8").unwrap();
+    /// assert_eq!(ip_network, IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap()));
+    /// assert_eq!(IpNetwork::from_str_strict("010.0.0.0/8"), Err(IpNetworkParseError::AddrParseError));
+    /// ```
+    pub fn from_str_strict(s: &str) -> Result<Self, IpNetworkParseError> {
+        let (ip, _) =
+            helpers::split_ip_optional_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
+
+        let ipv4_part = ip.rsplit(':').next().unwrap_or(ip);
+        if ipv4_part.contains('.')
+            && ipv4_part
+                .split('.')
+                .any(|octet| octet.len() > 1 && octet.starts_with('0'))
+        {
+            return Err(IpNetworkParseError::AddrParseError);
+        }
+
+        Self::from_str(s)
+    }
+
+    /// Converts string exported by some network devices, that contains netmask before network
+    /// address, to `IpNetwork`. Accepted formats are:
+    ///
+    /// * `/Y X.X.X.X` or `/Y X:X::X` – netmask with slash, one or more spaces and address,
+    /// * `Y X.X.X.X` or `Y X:X::X` – netmask without slash, one or more spaces and address,
+    /// * `X.X.X.X/Y` or `X:X::X/Y` – standard CIDR notation accepted by `from_str`.
+    ///
+    /// Network address must not have host bits set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::{IpNetwork, Ipv4Network};
+    ///
+    /// let ip_network = IpNetwork::from_str_vendor("/24 192.168.1.0").unwrap();
+    /// assert_eq!(ip_network, IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap()));
+    /// ```
+    pub fn from_str_vendor(s: &str) -> Result<Self, IpNetworkParseError> {
+        let (netmask, ip) = match s.split_once(' ') {
+            Some(parts) => parts,
+            None => return Self::from_str(s),
+        };
+
+        let (netmask, netmask_offset) = match netmask.strip_prefix('/') {
+            Some(netmask) => (netmask, 1),
+            None => (netmask, 0),
+        };
+
+        let network_address = IpAddr::from_str(ip.trim_start_matches(' '))
+            .map_err(|_| IpNetworkParseError::AddrParseError)?;
+        let netmask = helpers::parse_netmask(netmask, netmask_offset)?;
+
+        IpNetwork::new(network_address, netmask).map_err(IpNetworkParseError::IpNetworkError)
+    }
+
+    /// Converts string in CIDR notation (X.X.X.X/Y or X:X::X/Y) or plain IP address to `IpNetwork`.
+    /// Plain IP address is converted to network with netmask 32 for IPv4 or 128 for IPv6. Returned
+    /// flag is [`true`] when netmask was explicitly given in string.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::{IpNetwork, Ipv4Network};
+    ///
+    /// let (ip_network, has_netmask) = IpNetwork::parse_with_flag("192.168.1.1").unwrap();
+    /// assert_eq!(ip_network, IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32).unwrap()));
+    /// assert!(!has_netmask);
+    /// ```
+    pub fn parse_with_flag(s: &str) -> Result<(Self, bool), IpNetworkParseError> {
+        if s.contains('/') {
+            Self::from_str(s).map(|ip_network| (ip_network, true))
+        } else {
+            let ip = IpAddr::from_str(s).map_err(|_| IpNetworkParseError::AddrParseError)?;
+            Ok((Self::from(ip), false))
+        }
+    }
+
+    /// Parses every line of string in CIDR notation same as `from_str`. Instead of stopping on
+    /// first error, returns all successfully parsed networks together with list of errors and
+    /// their line numbers (starting from 1). Blank lines and lines starting with `#` are skipped,
+    /// whitespaces around networks are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip_network::{IpNetwork, IpNetworkParseError};
+    ///
+    /// let (networks, errors) = IpNetwork::parse_lines("# allowlist\n192.168.1.0/24\n\n10.0.0.1/8\n");
+    /// assert_eq!(networks, vec!["192.168.1.0/24".parse::<IpNetwork>()?]);
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].0, 4);
+    /// # Ok::<(), ip_network::IpNetworkParseError>(())
+    /// ```
+    pub fn parse_lines(s: &str) -> (Vec<Self>, Vec<(usize, IpNetworkParseError)>) {
+        let mut networks = Vec::new();
+        let mut errors = Vec::new();
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match Self::from_str(line) {
+                Ok(ip_network) => networks.push(ip_network),
+                Err(error) => errors.push((index + 1, error)),
+            }
+        }
+
+        (networks, errors)
+    }
+
     /// Return an iterator of the collapsed IpNetworks.
+    ///
+    /// Collapsed IPv4 networks are returned first, then IPv6 networks, both sorted by network
+    /// address and netmask. Result doesn't depend on order of given networks.
     pub fn collapse_addresses(addresses: &[Self]) -> Vec<Self> {
         let mut ipv4_networks = vec![];
         let mut ipv6_networks = vec![];
@@ -227,6 +532,96 @@ impl IpNetwork {
         );
         collapsed
     }
+
+    /// Constructs new `IpNetwork` from binary format used by PostgreSQL for [CIDR type]. This format
+    /// contains address family, netmask, CIDR flag, address length and address bytes.
+    ///
+    /// Returns error if bytes are not valid CIDR value or if host bits are set in network address.
+    ///
+    /// [CIDR type]: https://www.postgresql.org/docs/current/datatype-net-types.html#DATATYPE-CIDR
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::IpNetwork;
+    ///
+    /// let ip_network = IpNetwork::from_postgres_bytes(&[2, 24, 1, 4, 192, 168, 1, 0])?;
+    /// assert_eq!(ip_network, IpNetwork::new(Ipv4Addr::new(192, 168, 1, 0), 24)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn from_postgres_bytes(bytes: &[u8]) -> Result<Self, IpNetworkError> {
+        postgres_common::from_sql_ip_network(bytes)
+    }
+
+    /// Constructs new `IpNetwork` from IP version (`family` 4 or 6), network address bytes
+    /// (4 bytes for IPv4, 16 bytes for IPv6) and netmask, as used by many binary protocols.
+    ///
+    /// Returns error if family is unknown, address length doesn't match family, netmask is too big
+    /// or if host bits are set in network address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::IpNetwork;
+    ///
+    /// let ip_network = IpNetwork::from_family_bytes(4, &[192, 168, 1, 0], 24)?;
+    /// assert_eq!(ip_network, IpNetwork::new(Ipv4Addr::new(192, 168, 1, 0), 24)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn from_family_bytes(family: u8, addr: &[u8], prefix: u8) -> Result<Self, IpNetworkError> {
+        match family {
+            4 => {
+                let octets: [u8; 4] =
+                    addr.try_into()
+                        .map_err(|_| IpNetworkError::InvalidAddressLength {
+                            expected: Ipv4Network::LENGTH / 8,
+                            actual: addr.len(),
+                        })?;
+                Ok(IpNetwork::V4(Ipv4Network::new(
+                    Ipv4Addr::from(octets),
+                    prefix,
+                )?))
+            }
+            6 => {
+                let octets: [u8; 16] =
+                    addr.try_into()
+                        .map_err(|_| IpNetworkError::InvalidAddressLength {
+                            expected: Ipv6Network::LENGTH / 8,
+                            actual: addr.len(),
+                        })?;
+                Ok(IpNetwork::V6(Ipv6Network::new(
+                    Ipv6Addr::from(octets),
+                    prefix,
+                )?))
+            }
+            _ => Err(IpNetworkError::InvalidBytes(
+                "Address family is not IP version 4 or 6",
+            )),
+        }
+    }
+
+    /// Converts `IpNetwork` to binary format used by PostgreSQL for [CIDR type].
+    ///
+    /// [CIDR type]: https://www.postgresql.org/docs/current/datatype-net-types.html#DATATYPE-CIDR
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::IpNetwork;
+    ///
+    /// let ip_network = IpNetwork::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.to_postgres_bytes(), vec![2, 24, 1, 4, 192, 168, 1, 0]);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn to_postgres_bytes(&self) -> Vec<u8> {
+        match self {
+            IpNetwork::V4(ip_network) => postgres_common::to_sql_ipv4_network(ip_network).to_vec(),
+            IpNetwork::V6(ip_network) => postgres_common::to_sql_ipv6_network(ip_network).to_vec(),
+        }
+    }
 }
 
 impl fmt::Display for IpNetwork {
@@ -253,7 +648,9 @@ impl fmt::Display for IpNetwork {
 impl FromStr for IpNetwork {
     type Err = IpNetworkParseError;
 
-    /// Converts string in format IPv4 (X.X.X.X/Y) or IPv6 (X:X::X/Y) CIDR notation to `IpNetwork`.
+    /// Converts string in format IPv4 (X.X.X.X/Y or X.X.X.X/M.M.M.M with dotted network mask) or
+    /// IPv6 (X:X::X/Y) CIDR notation to `IpNetwork`. Plain IP address without netmask is converted
+    /// to network with netmask 32 for IPv4 or 128 for IPv6.
     ///
     /// # Examples
     ///
@@ -264,15 +661,10 @@ impl FromStr for IpNetwork {
     ///
     /// let ip_network = IpNetwork::from_str("192.168.1.0/24").unwrap();
     /// assert_eq!(ip_network, IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap()));
+    /// assert_eq!(IpNetwork::from_str("192.168.1.1").unwrap().netmask(), 32);
     /// ```
     fn from_str(s: &str) -> Result<IpNetwork, IpNetworkParseError> {
-        let (ip, netmask) =
-            helpers::split_ip_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
-
-        let network_address =
-            IpAddr::from_str(ip).map_err(|_| IpNetworkParseError::AddrParseError)?;
-        let netmask =
-            u8::from_str(netmask).map_err(|_| IpNetworkParseError::InvalidNetmaskFormat)?;
+        let (network_address, netmask) = Self::parse_address_netmask(s)?;
 
         IpNetwork::new(network_address, netmask).map_err(IpNetworkParseError::IpNetworkError)
     }
@@ -393,7 +785,10 @@ impl PartialOrd<IpNetwork> for Ipv6Network {
 #[cfg(test)]
 mod tests {
     use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
-    use crate::{IpNetwork, IpNetworkParseError, IpNetworkError, Ipv4Network, Ipv6Network};
+    use crate::{
+        IpNetwork, IpNetworkParseError, IpNetworkError, Ipv4Network, Ipv6MulticastScope,
+        Ipv6Network,
+    };
     use std::str::FromStr;
 
     fn return_test_ipv4_network() -> Ipv4Network {
@@ -436,6 +831,111 @@ mod tests {
         assert!(!ip_network.is_ipv4());
     }
 
+    #[test]
+    fn is_default() {
+        assert!(IpNetwork::from(Ipv4Network::DEFAULT_ROUTE).is_default());
+        assert!(IpNetwork::from(Ipv6Network::DEFAULT_ROUTE).is_default());
+        assert!(!IpNetwork::V4(return_test_ipv4_network()).is_default());
+        assert!(!IpNetwork::V6(return_test_ipv6_network()).is_default());
+    }
+
+    #[test]
+    fn multicast_scope() {
+        assert_eq!(
+            Some(Ipv6MulticastScope::Global),
+            IpNetwork::from_str("ff0e::/16").unwrap().multicast_scope()
+        );
+        assert_eq!(
+            Some(Ipv6MulticastScope::LinkLocal),
+            IpNetwork::from_str("ff02::1/128")
+                .unwrap()
+                .multicast_scope()
+        );
+        assert_eq!(
+            None,
+            IpNetwork::from_str("2001:db8::/32")
+                .unwrap()
+                .multicast_scope()
+        );
+        assert_eq!(
+            None,
+            IpNetwork::from_str("224.0.0.0/4")
+                .unwrap()
+                .multicast_scope()
+        );
+    }
+
+    #[test]
+    fn is_unspecified() {
+        assert!(IpNetwork::from_str("0.0.0.0/32").unwrap().is_unspecified());
+        assert!(!IpNetwork::from_str("0.0.0.0/0").unwrap().is_unspecified());
+        assert!(IpNetwork::from_str("::/128").unwrap().is_unspecified());
+        assert!(!IpNetwork::from_str("::/0").unwrap().is_unspecified());
+    }
+
+    #[test]
+    fn is_link_local() {
+        assert!(IpNetwork::from_str("169.254.1.0/24")
+            .unwrap()
+            .is_link_local());
+        assert!(!IpNetwork::from_str("192.168.0.0/16")
+            .unwrap()
+            .is_link_local());
+        assert!(IpNetwork::from_str("fe80::/64").unwrap().is_link_local());
+        assert!(!IpNetwork::from_str("2001:db8::/32")
+            .unwrap()
+            .is_link_local());
+    }
+
+    #[test]
+    fn contains_str() {
+        let ip_network = IpNetwork::V4(return_test_ipv4_network());
+        assert_eq!(Ok(true), ip_network.contains_str("192.168.1.1"));
+        assert_eq!(Ok(false), ip_network.contains_str("10.0.0.1"));
+        assert_eq!(Ok(false), ip_network.contains_str("::1"));
+        assert_eq!(
+            "192.168.1".parse::<IpAddr>().unwrap_err(),
+            ip_network.contains_str("192.168.1").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn cmp_specificity() {
+        let mut networks = [
+            "0.0.0.0/0",
+            "10.0.0.0/24",
+            "2001:db8::/32",
+            "::/0",
+            "10.0.0.1/32",
+            "192.168.0.0/24",
+            "2001:db8::1/128",
+            "10.0.0.0/32",
+            "1.0.0.0/32",
+        ]
+        .iter()
+        .map(|network| IpNetwork::from_str(network).unwrap())
+        .collect::<Vec<_>>();
+        networks.sort_by(IpNetwork::cmp_specificity);
+
+        assert_eq!(
+            vec![
+                "1.0.0.0/32",
+                "10.0.0.0/32",
+                "10.0.0.1/32",
+                "10.0.0.0/24",
+                "192.168.0.0/24",
+                "0.0.0.0/0",
+                "2001:db8::1/128",
+                "2001:db8::/32",
+                "::/0",
+            ],
+            networks
+                .iter()
+                .map(|network| network.to_string())
+                .collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn parse_ipv4() {
         let ip_network: IpNetwork = "192.168.0.0/16".parse().unwrap();
@@ -463,7 +963,7 @@ mod tests {
         let ip_network = "192.168.0.0/a".parse::<IpNetwork>();
         assert!(ip_network.is_err());
         assert_eq!(
-            IpNetworkParseError::InvalidNetmaskFormat,
+            IpNetworkParseError::InvalidNetmaskFormat { span: 12..13 },
             ip_network.unwrap_err()
         );
     }
@@ -495,6 +995,280 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_strict() {
+        assert_eq!(
+            IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap()),
+            IpNetwork::from_str_strict("10.0.0.0/8").unwrap()
+        );
+        assert_eq!(
+            IpNetwork::V6(
+                Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap()
+            ),
+            IpNetwork::from_str_strict("2001:db8::/32").unwrap()
+        );
+        assert_eq!(
+            IpNetwork::V6(
+                Ipv6Network::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0x0a00, 0x0001), 128)
+                    .unwrap()
+            ),
+            IpNetwork::from_str_strict("::ffff:10.0.0.1/128").unwrap()
+        );
+        assert_eq!(
+            IpNetwork::V6(
+                Ipv6Network::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0xdb8), 128).unwrap()
+            ),
+            IpNetwork::from_str_strict("::0db8/128").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_strict_leading_zeros() {
+        assert_eq!(
+            IpNetworkParseError::AddrParseError,
+            IpNetwork::from_str_strict("010.0.0.0/8").unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::AddrParseError,
+            IpNetwork::from_str_strict("192.168.001.0/24").unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::AddrParseError,
+            IpNetwork::from_str_strict("::ffff:010.0.0.1/128").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_with_flag() {
+        assert_eq!(
+            (
+                IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(1, 2, 3, 4), 32).unwrap()),
+                false
+            ),
+            IpNetwork::parse_with_flag("1.2.3.4").unwrap()
+        );
+        assert_eq!(
+            (
+                IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(1, 2, 3, 0), 24).unwrap()),
+                true
+            ),
+            IpNetwork::parse_with_flag("1.2.3.0/24").unwrap()
+        );
+        assert_eq!(
+            (
+                IpNetwork::V6(
+                    Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 128).unwrap()
+                ),
+                true
+            ),
+            IpNetwork::parse_with_flag("2001:db8::1/128").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_with_flag_invalid() {
+        assert_eq!(
+            IpNetworkParseError::AddrParseError,
+            IpNetwork::parse_with_flag("1.2.3").unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::IpNetworkError(IpNetworkError::HostBitsSet),
+            IpNetwork::parse_with_flag("1.2.3.4/24").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn to_other_family_deterministic() {
+        let networks = [
+            "0.0.0.0/0",
+            "10.0.0.0/8",
+            "192.168.1.0/24",
+            "255.255.255.255/32",
+        ];
+        for network in &networks {
+            let ip_network = IpNetwork::from_str(network).unwrap();
+            let other = ip_network.to_other_family_deterministic();
+            assert!(other.is_ipv6());
+            assert_eq!(ip_network.netmask() + 32, other.netmask());
+            assert_eq!(ip_network, other.to_other_family_deterministic());
+        }
+
+        assert_eq!(
+            IpNetwork::from_str("2001:db8:c0a8:100::/56").unwrap(),
+            IpNetwork::from_str("192.168.1.0/24")
+                .unwrap()
+                .to_other_family_deterministic()
+        );
+    }
+
+    #[test]
+    fn to_other_family_deterministic_from_ipv6() {
+        let ip_network = IpNetwork::from_str("2001:db8:c0a8:1ff::/64").unwrap();
+        assert_eq!(
+            IpNetwork::from_str("192.168.1.255/32").unwrap(),
+            ip_network.to_other_family_deterministic()
+        );
+
+        // netmask smaller than 32 is converted to netmask 0
+        let ip_network = IpNetwork::from_str("2001::/16").unwrap();
+        assert_eq!(
+            IpNetwork::from_str("0.0.0.0/0").unwrap(),
+            ip_network.to_other_family_deterministic()
+        );
+    }
+
+    #[test]
+    fn parse_lines() {
+        let input = "# allowlist\n\
+                     192.168.0.0/16\n\
+                     \n\
+                     \t2001:db8::/32  \n\
+                     # disabled: 10.0.0.0/8\n\
+                     10.0.0.0/33\n\
+                     172.16.0.0/12\n";
+
+        let (networks, errors) = IpNetwork::parse_lines(input);
+        assert_eq!(
+            networks,
+            vec![
+                IpNetwork::from_str("192.168.0.0/16").unwrap(),
+                IpNetwork::from_str("2001:db8::/32").unwrap(),
+                IpNetwork::from_str("172.16.0.0/12").unwrap(),
+            ]
+        );
+        assert_eq!(
+            errors,
+            vec![(
+                6,
+                IpNetworkParseError::IpNetworkError(IpNetworkError::NetmaskError(33))
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_without_netmask() {
+        assert_eq!(
+            IpNetwork::from(Ipv4Addr::new(10, 0, 0, 5)),
+            "10.0.0.5".parse::<IpNetwork>().unwrap()
+        );
+        assert_eq!(
+            IpNetwork::from(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+            "2001:db8::1".parse::<IpNetwork>().unwrap()
+        );
+        assert_eq!(
+            IpNetwork::from(Ipv4Addr::new(10, 0, 0, 5)),
+            IpNetwork::from_str_truncate("10.0.0.5").unwrap()
+        );
+        assert_eq!(
+            IpNetwork::from(Ipv4Addr::new(10, 0, 0, 5)),
+            IpNetwork::from_str_strict("10.0.0.5").unwrap()
+        );
+        assert_eq!(
+            IpNetworkParseError::InvalidFormatError,
+            "1.2.3.4/".parse::<IpNetwork>().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_dotted_netmask() {
+        let expected = IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap());
+        assert_eq!(expected, "10.0.0.0/255.0.0.0".parse::<IpNetwork>().unwrap());
+        assert_eq!(
+            expected,
+            IpNetwork::from_str_truncate("10.0.0.1/255.0.0.0").unwrap()
+        );
+        assert_eq!(
+            IpNetworkParseError::InvalidNetmaskFormat { span: 9..20 },
+            "10.0.0.0/255.0.255.0".parse::<IpNetwork>().unwrap_err()
+        );
+        // Dotted netmask is valid only for IPv4
+        assert_eq!(
+            IpNetworkParseError::InvalidNetmaskFormat { span: 11..24 },
+            "2001:db8::/255.255.255.0".parse::<IpNetwork>().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_lines_without_netmask() {
+        let (networks, errors) = IpNetwork::parse_lines("10.0.0.5\n2001:db8::1\n");
+        assert_eq!(
+            networks,
+            vec![
+                IpNetwork::from(Ipv4Addr::new(10, 0, 0, 5)),
+                IpNetwork::from(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+            ]
+        );
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn parse_lines_empty() {
+        let (networks, errors) = IpNetwork::parse_lines("");
+        assert!(networks.is_empty());
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn parse_octet_out_of_range() {
+        assert_eq!(
+            IpNetworkParseError::AddrOctetOutOfRange { span: 0..3 },
+            "300.0.0.0/8".parse::<IpNetwork>().unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::AddrParseError,
+            "1.2.3/8".parse::<IpNetwork>().unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::AddrParseError,
+            "2001:db8::g/32".parse::<IpNetwork>().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_vendor() {
+        let expected = IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 24).unwrap());
+        assert_eq!(
+            expected,
+            IpNetwork::from_str_vendor("/24 192.168.0.0").unwrap()
+        );
+        assert_eq!(
+            expected,
+            IpNetwork::from_str_vendor("24  192.168.0.0").unwrap()
+        );
+        assert_eq!(
+            expected,
+            IpNetwork::from_str_vendor("192.168.0.0/24").unwrap()
+        );
+        assert_eq!(
+            IpNetwork::V6(
+                Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap()
+            ),
+            IpNetwork::from_str_vendor("/32 2001:db8::").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_vendor_invalid() {
+        assert_eq!(
+            IpNetworkParseError::InvalidNetmaskFormat { span: 1..3 },
+            IpNetwork::from_str_vendor("/xx 192.168.0.0").unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::AddrParseError,
+            IpNetwork::from_str_vendor("/24 192.168.0").unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::IpNetworkError(IpNetworkError::HostBitsSet),
+            IpNetwork::from_str_vendor("/16 192.168.0.1").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_vendor_format_rejected_by_from_str() {
+        assert!(IpNetwork::from_str("/24 192.168.0.0").is_err());
+        assert!(IpNetwork::from_str("24 192.168.0.0").is_err());
+    }
+
     #[test]
     fn format_ipv4() {
         let ip_network = IpNetwork::V4(return_test_ipv4_network());
@@ -507,6 +1281,13 @@ mod tests {
         assert_eq!(ip_network.to_string(), "2001:db8::/32");
     }
 
+    #[test]
+    fn format_padding() {
+        let ip_network = IpNetwork::V4(return_test_ipv4_network());
+        assert_eq!(format!("{:>18}", ip_network), "    192.168.0.0/16");
+        assert_eq!(format!("{:>18}", ip_network).len(), 18);
+    }
+
     #[test]
     fn from_ipv4addr() {
         let ipv4addr = Ipv4Addr::new(1, 2, 3, 4);
@@ -607,6 +1388,81 @@ mod tests {
         assert!(ip_network_v6 > ip_network_v4);
     }
 
+    #[test]
+    fn postgres_bytes_ipv4() {
+        let ip_network = IpNetwork::V4(return_test_ipv4_network());
+        let bytes = ip_network.to_postgres_bytes();
+        assert_eq!(bytes, vec![2, 16, 1, 4, 192, 168, 0, 0]);
+        assert_eq!(Ok(ip_network), IpNetwork::from_postgres_bytes(&bytes));
+    }
+
+    #[test]
+    fn postgres_bytes_ipv6() {
+        let ip_network = IpNetwork::V6(return_test_ipv6_network());
+        let bytes = ip_network.to_postgres_bytes();
+        assert_eq!(20, bytes.len());
+        assert_eq!(Ok(ip_network), IpNetwork::from_postgres_bytes(&bytes));
+    }
+
+    #[test]
+    fn from_family_bytes() {
+        assert_eq!(
+            Ok(IpNetwork::V4(return_test_ipv4_network())),
+            IpNetwork::from_family_bytes(4, &[192, 168, 0, 0], 16)
+        );
+        assert_eq!(
+            Ok(IpNetwork::V6(return_test_ipv6_network())),
+            IpNetwork::from_family_bytes(
+                6,
+                &return_test_ipv6_network().network_address().octets(),
+                return_test_ipv6_network().netmask()
+            )
+        );
+    }
+
+    #[test]
+    fn from_family_bytes_invalid() {
+        assert_eq!(
+            Err(IpNetworkError::InvalidAddressLength {
+                expected: 4,
+                actual: 3
+            }),
+            IpNetwork::from_family_bytes(4, &[192, 168, 0], 16)
+        );
+        assert_eq!(
+            Err(IpNetworkError::InvalidAddressLength {
+                expected: 16,
+                actual: 4
+            }),
+            IpNetwork::from_family_bytes(6, &[192, 168, 0, 0], 16)
+        );
+        assert_eq!(
+            Err(IpNetworkError::InvalidBytes(
+                "Address family is not IP version 4 or 6"
+            )),
+            IpNetwork::from_family_bytes(2, &[192, 168, 0, 0], 16)
+        );
+        assert_eq!(
+            Err(IpNetworkError::NetmaskError(33)),
+            IpNetwork::from_family_bytes(4, &[192, 168, 0, 0], 33)
+        );
+        assert_eq!(
+            Err(IpNetworkError::HostBitsSet),
+            IpNetwork::from_family_bytes(4, &[192, 168, 0, 1], 16)
+        );
+    }
+
+    #[test]
+    fn postgres_bytes_inet() {
+        let ip_network = IpNetwork::from_postgres_bytes(&[2, 16, 0, 4, 192, 168, 0, 0]);
+        assert_eq!(
+            Err(IpNetworkError::InvalidBytes(
+                "This field is not CIDR type, probably INET type"
+            )),
+            ip_network
+        );
+    }
+
     #[test]
     fn collapse_addresses() {
         let addresses: Vec<_> = [
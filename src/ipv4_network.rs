@@ -1,13 +1,43 @@
 use std::cmp;
 use std::fmt;
-use std::net::Ipv4Addr;
+use std::iter;
+use std::net::{AddrParseError, Ipv4Addr};
+use std::ops::BitAnd;
 use std::str::FromStr;
 use std::hash::{Hash, Hasher};
-use crate::{IpNetworkError, IpNetworkParseError};
+use crate::{Contained, DisplayHost, IpNetworkError, IpNetworkParseError, Ipv6Network};
 use crate::helpers;
 use crate::iterator;
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+
+/// IPv4 network mask, that can be converted to prefix length, dotted IPv4 address or integer form.
+/// Returned by [`Ipv4Network::netmask_info`].
+///
+/// [`Ipv4Network::netmask_info`]: struct.Ipv4Network.html#method.netmask_info
+#[derive(Copy, PartialEq, Eq, Clone, Hash, Debug)]
+pub struct Netmask(u8);
+
+impl Netmask {
+    /// Returns network mask as prefix length (number of network bits).
+    #[inline]
+    pub const fn prefix_len(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns network mask as IPv4 address (for example 255.255.255.0 for /24).
+    #[inline]
+    pub const fn to_ipv4(&self) -> Ipv4Addr {
+        let [a, b, c, d] = self.to_u32().to_be_bytes();
+        Ipv4Addr::new(a, b, c, d)
+    }
+
+    /// Returns network mask as integer (for example 0xffffff00 for /24).
+    #[inline]
+    pub const fn to_u32(&self) -> u32 {
+        helpers::bite_mask(self.0)
+    }
+}
 
 /// IPv4 Network.
 #[derive(Clone, Copy, Debug, Eq, PartialOrd, Ord)]
@@ -61,6 +91,43 @@ impl Ipv4Network {
         })
     }
 
+    /// Constructs new `Ipv4Network` based on [`Ipv4Addr`] and `netmask` for use in `const` items.
+    /// Invalid network is then reported as compile error.
+    ///
+    /// [`Ipv4Addr`]: https://doc.rust-lang.org/std/net/struct.Ipv4Addr.html
+    ///
+    /// # Panics
+    ///
+    /// This method panics when netmask is bigger than 32 or when host bits are set in
+    /// `network_address`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// const PRIVATE: Ipv4Network = Ipv4Network::new_const(Ipv4Addr::new(10, 0, 0, 0), 8);
+    /// assert!(PRIVATE.contains(Ipv4Addr::new(10, 1, 2, 3)));
+    /// ```
+    ///
+    /// Netmask out of range fails to build:
+    ///
+    /// ```compile_fail
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// const INVALID: Ipv4Network = Ipv4Network::new_const(Ipv4Addr::new(10, 0, 0, 0), 33);
+    /// # let _ = INVALID;
+    /// ```
+    pub const fn new_const(network_address: Ipv4Addr, netmask: u8) -> Self {
+        match Self::new(network_address, netmask) {
+            Ok(network) => network,
+            Err(IpNetworkError::NetmaskError(_)) => panic!("netmask is bigger than 32"),
+            Err(_) => panic!("host bits are set in network address"),
+        }
+    }
+
     /// Constructs new `Ipv4Network` based on [`Ipv4Addr`] and `netmask` with truncating host bits
     /// from given `network_address`.
     ///
@@ -79,13 +146,18 @@ impl Ipv4Network {
     /// assert_eq!(ip_network.netmask(), 24);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn new_truncate(network_address: Ipv4Addr, netmask: u8) -> Result<Self, IpNetworkError> {
+    pub const fn new_truncate(
+        network_address: Ipv4Addr,
+        netmask: u8,
+    ) -> Result<Self, IpNetworkError> {
         if netmask > Self::LENGTH {
             return Err(IpNetworkError::NetmaskError(netmask));
         }
 
-        let network_address =
-            Ipv4Addr::from(u32::from(network_address) & helpers::bite_mask(netmask));
+        let [a, b, c, d] = (u32::from_be_bytes(network_address.octets())
+            & helpers::bite_mask(netmask))
+        .to_be_bytes();
+        let network_address = Ipv4Addr::new(a, b, c, d);
 
         Ok(Self {
             network_address,
@@ -93,7 +165,14 @@ impl Ipv4Network {
         })
     }
 
-    /// Returns network IP address (first address in range).
+    /// Constructs new `Ipv4Network` based on [`Ipv4Addr`] and network mask in dotted form (like
+    /// `255.255.255.0`).
+    ///
+    /// Returns `IpNetworkError::NonContiguousNetmask` when mask bits are not contiguous and the
+    /// same errors as [`new`] otherwise.
+    ///
+    /// [`Ipv4Addr`]: https://doc.rust-lang.org/std/net/struct.Ipv4Addr.html
+    /// [`new`]: #method.new
     ///
     /// # Examples
     ///
@@ -101,16 +180,26 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
-    /// assert_eq!(ip_network.network_address(), Ipv4Addr::new(192, 168, 1, 0));
+    /// let ip_network = Ipv4Network::with_netmask(Ipv4Addr::new(192, 168, 1, 0), Ipv4Addr::new(255, 255, 255, 0))?;
+    /// assert_eq!(ip_network.netmask(), 24);
+    /// assert!(Ipv4Network::with_netmask(Ipv4Addr::new(192, 168, 1, 0), Ipv4Addr::new(255, 255, 0, 255)).is_err());
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    #[inline]
-    pub fn network_address(&self) -> Ipv4Addr {
-        self.network_address
+    pub fn with_netmask(
+        network_address: Ipv4Addr,
+        netmask: Ipv4Addr,
+    ) -> Result<Self, IpNetworkError> {
+        let netmask = helpers::netmask_from_ipv4(netmask)
+            .ok_or(IpNetworkError::NonContiguousNetmask(netmask))?;
+        Self::new(network_address, netmask)
     }
 
-    /// Returns broadcast address of network (last address in range).
+    /// Constructs new `Ipv4Network` from wildcard string (like `192.168.*.*`), where trailing `*`
+    /// octets are considered as host bits. So `192.168.*.*` is converted to `192.168.0.0/16` and
+    /// address without any wildcard to network with netmask 32.
+    ///
+    /// Returns error if `*` is not in trailing position (like `192.*.1.*`) or when address is
+    /// not valid.
     ///
     /// # Examples
     ///
@@ -118,15 +207,49 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
-    /// assert_eq!(ip_network.broadcast_address(), Ipv4Addr::new(192, 168, 1, 255));
-    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// let ip_network = Ipv4Network::from_wildcard("192.168.*.*")?;
+    /// assert_eq!(ip_network.network_address(), Ipv4Addr::new(192, 168, 0, 0));
+    /// assert_eq!(ip_network.netmask(), 16);
+    /// # Ok::<(), ip_network::IpNetworkParseError>(())
     /// ```
-    pub fn broadcast_address(&self) -> Ipv4Addr {
-        Ipv4Addr::from(u32::from(self.network_address) | !helpers::bite_mask(self.netmask))
+    pub fn from_wildcard(s: &str) -> Result<Self, IpNetworkParseError> {
+        let octets = s.split('.').collect::<Vec<_>>();
+        if octets.len() != 4 {
+            return Err(IpNetworkParseError::AddrParseError);
+        }
+
+        let wildcards = octets
+            .iter()
+            .rev()
+            .take_while(|octet| **octet == "*")
+            .count();
+        if octets[..4 - wildcards]
+            .iter()
+            .any(|octet| octet.contains('*'))
+        {
+            return Err(IpNetworkParseError::InvalidFormatError);
+        }
+
+        let address = octets
+            .iter()
+            .map(|octet| if *octet == "*" { "0" } else { octet })
+            .collect::<Vec<_>>()
+            .join(".");
+        let network_address =
+            Ipv4Addr::from_str(&address).map_err(|_| IpNetworkParseError::AddrParseError)?;
+
+        Ok(Self {
+            network_address,
+            netmask: Self::LENGTH - 8 * wildcards as u8,
+        })
     }
 
-    /// Returns network mask as integer.
+    /// Constructs new `Ipv4Network` from value packed by [`to_packed`] method.
+    ///
+    /// Returns error if netmask is bigger than 32, when host bits are set in network address or
+    /// when padding bits are not zero.
+    ///
+    /// [`to_packed`]: #method.to_packed
     ///
     /// # Examples
     ///
@@ -134,16 +257,24 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
-    /// assert_eq!(ip_network.netmask(), 24);
+    /// let ip_network = Ipv4Network::from_packed(0xC0A8_0100_0000_0018)?;
+    /// assert_eq!(ip_network, Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    #[inline]
-    pub fn netmask(&self) -> u8 {
-        self.netmask
+    pub fn from_packed(packed: u64) -> Result<Self, IpNetworkError> {
+        if packed & 0xFFFF_FF00 != 0 {
+            return Err(IpNetworkError::InvalidBytes(
+                "Padding bits are set in packed IPv4 network",
+            ));
+        }
+
+        let network_address = Ipv4Addr::from((packed >> 32) as u32);
+        Self::new(network_address, packed as u8)
     }
 
-    /// Returns network mask as IPv4 address.
+    /// Returns network packed to one `u64` value. Network address is stored in upper 32 bits,
+    /// netmask in lowest 8 bits and remaining bits are zero, so ordering of packed values is the
+    /// same as ordering of networks.
     ///
     /// # Examples
     ///
@@ -152,17 +283,17 @@ impl Ipv4Network {
     /// use ip_network::Ipv4Network;
     ///
     /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
-    /// assert_eq!(ip_network.full_netmask(), Ipv4Addr::new(255, 255, 255, 0));
+    /// assert_eq!(0xC0A8_0100_0000_0018, ip_network.to_packed());
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn full_netmask(&self) -> Ipv4Addr {
-        Ipv4Addr::from(helpers::bite_mask(self.netmask))
+    pub fn to_packed(&self) -> u64 {
+        u64::from(u32::from(self.network_address)) << 32 | u64::from(self.netmask)
     }
 
-    /// Returns [`true`] if given [`IPv4Addr`] is inside this network.
+    /// Constructs new `Ipv4Network` from network address as `u32` and netmask, mirroring
+    /// `Ipv4Addr::from_bits`. Returns the same errors as [`new`].
     ///
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
-    /// [`Ipv4Addr`]: https://doc.rust-lang.org/std/net/struct.Ipv4Addr.html
+    /// [`new`]: #method.new
     ///
     /// # Examples
     ///
@@ -170,20 +301,16 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
-    /// assert!(ip_network.contains(Ipv4Addr::new(192, 168, 1, 2)));
-    /// assert!(!ip_network.contains(Ipv4Addr::new(192, 168, 2, 2)));
+    /// let ip_network = Ipv4Network::from_bits(0xC0A8_0100, 24)?;
+    /// assert_eq!(ip_network, Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn contains(&self, ip: Ipv4Addr) -> bool {
-        u32::from(ip) & helpers::bite_mask(self.netmask) == u32::from(self.network_address)
+    #[inline]
+    pub fn from_bits(bits: u32, netmask: u8) -> Result<Self, IpNetworkError> {
+        Self::new(Ipv4Addr::from(bits), netmask)
     }
 
-    /// Returns iterator over host IP addresses in range (without network and broadcast address). You
-    /// can also use this method to check how much hosts address are in range by calling [`len()`] method
-    /// on iterator (see Examples).
-    ///
-    /// [`len()`]: https://doc.rust-lang.org/std/iter/trait.ExactSizeIterator.html#method.len
+    /// Returns network address as `u32` and netmask, mirroring `Ipv4Addr::to_bits`.
     ///
     /// # Examples
     ///
@@ -191,63 +318,69 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// let ip = Ipv4Addr::new(192, 168, 1, 0);
-    /// let mut hosts = Ipv4Network::new(ip, 24)?.hosts();
-    /// assert_eq!(254, hosts.len());
-    /// assert_eq!(hosts.next().unwrap(), Ipv4Addr::new(192, 168, 1, 1));
-    /// assert_eq!(hosts.last().unwrap(), Ipv4Addr::new(192, 168, 1, 254));
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!((0xC0A8_0100, 24), ip_network.to_bits());
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn hosts(&self) -> impl ExactSizeIterator<Item = Ipv4Addr> {
-        iterator::Ipv4RangeIterator::hosts(*self)
+    #[inline]
+    pub fn to_bits(&self) -> (u32, u8) {
+        (u32::from(self.network_address), self.netmask)
     }
 
-    /// Returns network with smaller netmask by one. If netmask is already zero, `None` will be returned.
+    /// Converts network to IPv4-mapped IPv6 network (::ffff:a.b.c.d) with netmask bigger by 96.
+    ///
+    /// This format is defined by [IETF RFC 4291].
+    ///
+    /// [IETF RFC 4291]: https://tools.ietf.org/html/rfc4291#section-2.5.5.2
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::net::Ipv4Addr;
-    /// use ip_network::Ipv4Network;
+    /// use std::net::{Ipv4Addr, Ipv6Addr};
+    /// use ip_network::{Ipv4Network, Ipv6Network};
     ///
-    /// let ip = Ipv4Addr::new(192, 168, 1, 0);
-    /// let mut hosts = Ipv4Network::new(ip, 24)?;
-    /// assert_eq!(hosts.supernet(), Some(Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 23)?));
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.to_ipv6_mapped(), Ipv6Network::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0xc0a8, 0x100), 120)?);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn supernet(&self) -> Option<Self> {
-        if self.netmask == 0 {
-            None
-        } else {
-            Some(Self::new_truncate(self.network_address, self.netmask - 1).unwrap())
+    pub fn to_ipv6_mapped(&self) -> Ipv6Network {
+        Ipv6Network {
+            network_address: self.network_address.to_ipv6_mapped(),
+            netmask: 96 + self.netmask,
         }
     }
 
-    /// Returns iterator over networks with bigger netmask by one. If netmask is already 32,
-    /// iterator is empty.
+    /// Converts network to IPv4-compatible IPv6 network (::a.b.c.d) with netmask bigger by 96.
+    ///
+    /// This format is deprecated by [IETF RFC 4291], use [`to_ipv6_mapped`] instead when possible.
+    ///
+    /// [IETF RFC 4291]: https://tools.ietf.org/html/rfc4291#section-2.5.5.1
+    /// [`to_ipv6_mapped`]: #method.to_ipv6_mapped
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::net::Ipv4Addr;
-    /// use ip_network::Ipv4Network;
+    /// use std::net::{Ipv4Addr, Ipv6Addr};
+    /// use ip_network::{Ipv4Network, Ipv6Network};
     ///
     /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
-    /// let mut iterator = ip_network.subnets();
-    /// assert_eq!(iterator.next().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 25)?);
-    /// assert_eq!(iterator.last().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25)?);
+    /// assert_eq!(ip_network.to_ipv6_compatible(), Ipv6Network::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0xc0a8, 0x100), 120)?);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn subnets(&self) -> impl ExactSizeIterator<Item = Ipv4Network> {
-        let new_netmask = cmp::min(self.netmask + 1, Self::LENGTH);
-        iterator::Ipv4NetworkIterator::new(*self, new_netmask)
+    pub fn to_ipv6_compatible(&self) -> Ipv6Network {
+        Ipv6Network {
+            network_address: self.network_address.to_ipv6_compatible(),
+            netmask: 96 + self.netmask,
+        }
     }
 
-    /// Returns `Ipv4NetworkIterator` over networks with defined netmask.
-    ///
-    /// # Panics
+    /// Converts network to IPv6 network with netmask bigger by 96. When `mapped` is [`true`],
+    /// returns IPv4-mapped network (`::ffff:0:0/96` prefix, [IETF RFC 4291 section 2.5.5.2]),
+    /// otherwise IPv4-compatible network (`::/96` prefix, [IETF RFC 4291 section 2.5.5.1]).
     ///
-    /// This method panics when prefix is bigger than 32 or when prefix is lower or equal than netmask.
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// [IETF RFC 4291 section 2.5.5.2]: https://tools.ietf.org/html/rfc4291#section-2.5.5.2
+    /// [IETF RFC 4291 section 2.5.5.1]: https://tools.ietf.org/html/rfc4291#section-2.5.5.1
     ///
     /// # Examples
     ///
@@ -255,19 +388,20 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// let ip = Ipv4Addr::new(192, 168, 1, 0);
-    /// let mut iterator = Ipv4Network::new(ip, 24)?.subnets_with_prefix(25);
-    /// assert_eq!(iterator.next().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 25)?);
-    /// assert_eq!(iterator.last().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25)?);
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.to_ipv6(true), ip_network.to_ipv6_mapped());
+    /// assert_eq!(ip_network.to_ipv6(false), ip_network.to_ipv6_compatible());
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn subnets_with_prefix(&self, prefix: u8) -> impl ExactSizeIterator<Item = Ipv4Network> {
-        iterator::Ipv4NetworkIterator::new(*self, prefix)
+    pub fn to_ipv6(&self, mapped: bool) -> Ipv6Network {
+        if mapped {
+            self.to_ipv6_mapped()
+        } else {
+            self.to_ipv6_compatible()
+        }
     }
 
-    /// Returns [`true`] for the default route network (0.0.0.0/0), that contains all IPv4 addresses.
-    ///
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Returns network IP address (first address in range).
     ///
     /// # Examples
     ///
@@ -275,19 +409,16 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 0)?.is_default_route());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.network_address(), Ipv4Addr::new(192, 168, 1, 0));
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_default_route(&self) -> bool {
-        self.netmask == 0
+    #[inline]
+    pub const fn network_address(&self) -> Ipv4Addr {
+        self.network_address
     }
 
-    /// Returns [`true`] for network in local identification range (0.0.0.0/8).
-    ///
-    /// This property is defined by [IETF RFC 1122].
-    ///
-    /// [IETF RFC 1122]: https://tools.ietf.org/html/rfc1122
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Returns octets of network IP address.
     ///
     /// # Examples
     ///
@@ -295,20 +426,17 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 8)?.is_local_identification());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.octets(), [192, 168, 1, 0]);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_local_identification(&self) -> bool {
-        self.network_address.octets()[0] == 0 && self.netmask >= 8
+    #[inline]
+    pub const fn octets(&self) -> [u8; 4] {
+        self.network_address.octets()
     }
 
-    /// Returns [`true`] for the special 'unspecified' network (0.0.0.0/32).
-    ///
-    /// This property is defined in _UNIX Network Programming, Second Edition_,
-    /// W. Richard Stevens, p. 891; see also [ip7].
-    ///
-    /// [ip7]: http://man7.org/linux/man-pages/man7/ip.7.html
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Returns object implementing `Display` that formats network without netmask when netmask
+    /// is 32 (network contains just one address), otherwise in CIDR notation.
     ///
     /// # Examples
     ///
@@ -316,39 +444,38 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 32)?.is_unspecified());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32)?;
+    /// assert_eq!(ip_network.display_host().to_string(), "192.168.1.1");
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_unspecified(&self) -> bool {
-        self.netmask == Self::LENGTH && self.network_address.is_unspecified()
+    #[inline]
+    pub fn display_host(&self) -> DisplayHost<'_, Self> {
+        DisplayHost::new(self)
     }
 
-    /// Returns [`true`] if this network is inside loopback address range (127.0.0.0/8).
-    ///
-    /// This property is defined by [IETF RFC 1122].
-    ///
-    /// [IETF RFC 1122]: https://tools.ietf.org/html/rfc1122
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Writes network in format X.X.X.X/Y (CIDR notation) to any `core::fmt::Write` implementation
+    /// without allocation, so it can be used also with fixed capacity buffers.
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::fmt::Write;
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 0), 8)?.is_loopback());
-    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// let mut output = String::new();
+    /// Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?.write_cidr(&mut output)?;
+    /// assert_eq!(output, "192.168.1.0/24");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn is_loopback(&self) -> bool {
-        self.network_address.is_loopback()
+    pub fn write_cidr<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        write!(w, "{}/{}", self.network_address, self.netmask)
     }
 
-    /// Returns [`true`] if this is a broadcast network (255.255.255.255/32).
-    ///
-    /// A broadcast address has all octets set to 255 as defined in [IETF RFC 919].
-    ///
-    /// [IETF RFC 919]: https://tools.ietf.org/html/rfc919
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Returns hash of network that doesn't depend on `RandomState` or platform, so it can be
+    /// stored in persistent structures. Hash is computed by 64-bit FNV-1a algorithm over
+    /// network address octets followed by netmask and is guaranteed to not change in future
+    /// versions.
     ///
     /// # Examples
     ///
@@ -356,23 +483,18 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 255), 32)?.is_broadcast());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8)?;
+    /// assert_eq!(ip_network.stable_hash(), 0x6391_2782_0a46_0395);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_broadcast(&self) -> bool {
-        self.network_address.is_broadcast()
+    pub fn stable_hash(&self) -> u64 {
+        let mut bytes = [0; 5];
+        bytes[..4].copy_from_slice(&self.network_address.octets());
+        bytes[4] = self.netmask;
+        helpers::fnv1a_64(&bytes)
     }
 
-    /// Returns [`true`] if this whole network range is inside private address ranges.
-    ///
-    /// The private address ranges are defined in [IETF RFC 1918] and include:
-    ///
-    ///  - 10.0.0.0/8
-    ///  - 172.16.0.0/12
-    ///  - 192.168.0.0/16
-    ///
-    /// [IETF RFC 1918]: https://tools.ietf.org/html/rfc1918
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Returns broadcast address of network (last address in range).
     ///
     /// # Examples
     ///
@@ -380,25 +502,17 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?.is_private());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.broadcast_address(), Ipv4Addr::new(192, 168, 1, 255));
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_private(&self) -> bool {
-        let octets = self.network_address.octets();
-        match octets[0] {
-            10 if self.netmask >= 8 => true,
-            172 if octets[1] >= 16 && octets[1] <= 31 && self.netmask >= 12 => true,
-            192 if octets[1] == 168 && self.netmask >= 16 => true,
-            _ => false,
-        }
+    pub fn broadcast_address(&self) -> Ipv4Addr {
+        Ipv4Addr::from(u32::from(self.network_address) | !helpers::bite_mask(self.netmask))
     }
 
-    /// Returns [`true`] if this whole network is inside IETF Protocol Assignments range (192.0.0.0/24).
-    ///
-    /// This property is defined by [IETF RFC 6890, Section 2.1].
+    /// Returns first IP address in range, same as [`network_address`].
     ///
-    /// [IETF RFC 6890, Section 2.1]: https://tools.ietf.org/html/rfc6890#section-2.1
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// [`network_address`]: #method.network_address
     ///
     /// # Examples
     ///
@@ -406,20 +520,18 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(192, 0, 0, 0), 24)?.is_ietf_protocol_assignments());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.first_address(), Ipv4Addr::new(192, 168, 1, 0));
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_ietf_protocol_assignments(&self) -> bool {
-        let octets = self.network_address.octets();
-        octets[0] == 192 && octets[1] == 0 && octets[2] == 0 && self.netmask >= 24
+    #[inline]
+    pub const fn first_address(&self) -> Ipv4Addr {
+        self.network_address
     }
 
-    /// Returns [`true`] if this whole network is inside Shared Address Space (100.64.0.0/10).
-    ///
-    /// This property is defined by [IETF RFC 6598].
+    /// Returns last IP address in range, same as [`broadcast_address`].
     ///
-    /// [IETF RFC 6598]: https://tools.ietf.org/html/rfc6598
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// [`broadcast_address`]: #method.broadcast_address
     ///
     /// # Examples
     ///
@@ -427,20 +539,16 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(100, 64, 0, 0), 10)?.is_shared_address_space());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.last_address(), Ipv4Addr::new(192, 168, 1, 255));
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_shared_address_space(&self) -> bool {
-        let octets = self.network_address.octets();
-        octets[0] == 100 && octets[1] & 0xc0 == 64
+    #[inline]
+    pub fn last_address(&self) -> Ipv4Addr {
+        self.broadcast_address()
     }
 
-    /// Returns [`true`] if the network is is inside link-local range (169.254.0.0/16).
-    ///
-    /// This property is defined by [IETF RFC 3927].
-    ///
-    /// [IETF RFC 3927]: https://tools.ietf.org/html/rfc3927
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Returns network mask as integer.
     ///
     /// # Examples
     ///
@@ -448,20 +556,16 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(169, 254, 1, 0), 24)?.is_link_local());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.netmask(), 24);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_link_local(&self) -> bool {
-        self.network_address.is_link_local() && self.netmask >= 16
+    #[inline]
+    pub const fn netmask(&self) -> u8 {
+        self.netmask
     }
 
-    /// Returns [`true`] if this whole network is inside multicast address range (224.0.0.0/4).
-    ///
-    /// Multicast network addresses have a most significant octet between 224 and 239,
-    /// and is defined by [IETF RFC 5771].
-    ///
-    /// [IETF RFC 5771]: https://tools.ietf.org/html/rfc5771
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Returns number of network bits, that is the same value as netmask.
     ///
     /// # Examples
     ///
@@ -469,19 +573,16 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(224, 168, 1, 0), 24)?.is_multicast());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.network_bits(), 24);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_multicast(&self) -> bool {
-        self.network_address.octets()[0] & 0xf0 == 224 && self.netmask >= 4
+    #[inline]
+    pub const fn network_bits(&self) -> u8 {
+        self.netmask
     }
 
-    /// Returns [`true`] if this whole network is inside benchmarking address range (198.18.0.0/15).
-    ///
-    /// This property is defined by [IETF RFC 2544].
-    ///
-    /// [IETF RFC 2544]: https://tools.ietf.org/html/rfc2544
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Returns number of host bits, that is 32 minus netmask.
     ///
     /// # Examples
     ///
@@ -489,23 +590,16 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(198, 19, 1, 0), 24)?.is_benchmarking());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.host_bits(), 8);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_benchmarking(&self) -> bool {
-        // Not necessary to check netmask
-        let octets = self.network_address.octets();
-        octets[0] == 198 && octets[1] & 0xfe == 18
+    #[inline]
+    pub const fn host_bits(&self) -> u8 {
+        Self::LENGTH - self.netmask
     }
 
-    /// Returns [`true`] if this whole network is inside reserved address range (240.0.0.0/4), except
-    /// broadcast address (255.255.255.255/32).
-    ///
-    /// Reserved network addresses have a most significant octet between 240 and 255,
-    /// and is defined by [IETF RFC 1112].
-    ///
-    /// [IETF RFC 1112]: https://tools.ietf.org/html/rfc1112
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Returns number of addresses in network (including network and broadcast address).
     ///
     /// # Examples
     ///
@@ -513,25 +607,20 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(240, 168, 1, 0), 24)?.is_reserved());
-    /// assert!(!Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 255), 32)?.is_reserved());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.num_addresses(), 256);
+    /// assert_eq!(Ipv4Network::DEFAULT_ROUTE.num_addresses(), 1 << 32);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_reserved(&self) -> bool {
-        // Not necessary to check netmask
-        self.network_address.octets()[0] & 0xf0 == 240 && !self.network_address.is_broadcast()
+    #[inline]
+    pub const fn num_addresses(&self) -> u64 {
+        1 << (Self::LENGTH - self.netmask)
     }
 
-    /// Returns [`true`] if this network is in a range designated for documentation.
-    ///
-    /// This is defined in [IETF RFC 5737]:
-    ///
-    /// - 192.0.2.0/24 (TEST-NET-1)
-    /// - 198.51.100.0/24 (TEST-NET-2)
-    /// - 203.0.113.0/24 (TEST-NET-3)
+    /// Returns `n`-th IP address in network (counted from network address) or `None` when `n` is
+    /// not smaller than [`num_addresses`]. Unlike iterating, this is constant time operation.
     ///
-    /// [IETF RFC 5737]: https://tools.ietf.org/html/rfc5737
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// [`num_addresses`]: #method.num_addresses
     ///
     /// # Examples
     ///
@@ -539,32 +628,20 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 0), 24)?.is_documentation());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.nth_address(10), Some(Ipv4Addr::new(192, 168, 1, 10)));
+    /// assert_eq!(ip_network.nth_address(256), None);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_documentation(&self) -> bool {
-        self.network_address.is_documentation() && self.netmask >= 24
+    pub fn nth_address(&self, n: u32) -> Option<Ipv4Addr> {
+        if u64::from(n) < self.num_addresses() {
+            Some(Ipv4Addr::from(u32::from(self.network_address) | n))
+        } else {
+            None
+        }
     }
 
-    /// Returns [`true`] if the network appears to be globally routable.
-    /// See [IANA IPv4 Special-Purpose Address Registry][ipv4-sr].
-    ///
-    /// The following return [`false`]:
-    ///
-    /// - local identification (0.0.0.0/8)
-    /// - private address (10.0.0.0/8, 172.16.0.0/12 and 192.168.0.0/16)
-    /// - Shared Address Space (100.64.0.0/10)
-    /// - the loopback address (127.0.0.0/8)
-    /// - the link-local address (169.254.0.0/16)
-    /// - IETF Protocol Assignments	(192.0.0.0/24, except 192.0.0.9/32 and 192.0.0.10/32)
-    /// - the broadcast address (255.255.255.255/32)
-    /// - test addresses used for documentation (192.0.2.0/24, 198.51.100.0/24 and 203.0.113.0/24)
-    /// - benchmarking (198.18.0.0/15)
-    /// - reserved range (240.0.0.0/4)
-    ///
-    /// [ipv4-sr]: https://www.iana.org/assignments/iana-ipv4-special-registry/iana-ipv4-special-registry.xhtml
-    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
-    /// [`false`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// Returns network mask as IPv4 address.
     ///
     /// # Examples
     ///
@@ -572,37 +649,15 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert!(!Ipv4Network::new(Ipv4Addr::new(10, 254, 0, 0), 16)?.is_global());
-    /// assert!(!Ipv4Network::new(Ipv4Addr::new(192, 168, 10, 65), 32)?.is_global());
-    /// assert!(!Ipv4Network::new(Ipv4Addr::new(172, 16, 10, 65), 32)?.is_global());
-    /// assert!(!Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 32)?.is_global());
-    /// assert!(Ipv4Network::new(Ipv4Addr::new(80, 9, 12, 3), 32)?.is_global());
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.full_netmask(), Ipv4Addr::new(255, 255, 255, 0));
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_global(&self) -> bool {
-        let octets = self.network_address.octets();
-        // These address are only two globally routable from IETF Protocol Assignments.
-        if self.netmask == 32 && (octets == [192, 0, 0, 9] || octets == [192, 0, 0, 10]) {
-            return true;
-        }
-
-        !self.is_local_identification()
-            && !self.is_private()
-            && !self.is_ietf_protocol_assignments()
-            && !self.is_shared_address_space()
-            && !self.is_loopback()
-            && !self.is_link_local()
-            && !self.is_broadcast()
-            && !self.is_documentation()
-            && !self.is_benchmarking()
-            && !self.is_reserved()
+    pub fn full_netmask(&self) -> Ipv4Addr {
+        Ipv4Addr::from(helpers::bite_mask(self.netmask))
     }
 
-    /// Return a vector of the summarized network range given the first and last IPv4 addresses.
-    /// Implementation of this method was inspired by Python [`ipaddress.summarize_address_range`]
-    /// method. If first IP address is bigger than last, empty vector is returned.
-    ///
-    /// [`ipaddress.summarize_address_range`]: https://docs.python.org/3/library/ipaddress.html#ipaddress.summarize_address_range
+    /// Returns inverted network mask (wildcard mask, as used in Cisco ACLs) as IPv4 address.
     ///
     /// # Examples
     ///
@@ -610,52 +665,17 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// let ranges = Ipv4Network::summarize_address_range(
-    ///     Ipv4Addr::new(10, 254, 0, 0),
-    ///     Ipv4Addr::new(10, 255, 255, 255),
-    /// );
-    ///
-    /// assert_eq!(Ipv4Network::new(Ipv4Addr::new(10, 254, 0, 0), 15)?, ranges[0]);
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.hostmask(), Ipv4Addr::new(0, 0, 0, 255));
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn summarize_address_range(first: Ipv4Addr, last: Ipv4Addr) -> Vec<Self> {
-        let mut first_int = u32::from(first);
-        let last_int = u32::from(last);
-
-        let mut vector = vec![];
-
-        while first_int <= last_int {
-            let bit_length_diff = if last_int - first_int == u32::MAX {
-                Self::LENGTH
-            } else {
-                helpers::bit_length(last_int - first_int + 1) - 1
-            };
-
-            let nbits = cmp::min(first_int.trailing_zeros() as u8, bit_length_diff);
-
-            vector.push(Self {
-                network_address: Ipv4Addr::from(first_int),
-                netmask: Self::LENGTH - nbits,
-            });
-
-            if nbits == Self::LENGTH {
-                break;
-            }
-
-            match first_int.checked_add(1 << nbits) {
-                Some(x) => first_int = x,
-                None => break,
-            }
-        }
-
-        vector
+    pub fn hostmask(&self) -> Ipv4Addr {
+        Ipv4Addr::from(!helpers::bite_mask(self.netmask))
     }
 
-    /// Return an iterator of the collapsed Ipv4Networks.
+    /// Returns network mask as [`Netmask`], that can be converted to all network mask forms.
     ///
-    /// Implementation of this method was inspired by Python [`ipaddress.collapse_addresses`]
-    ///
-    /// [`ipaddress.collapse_addresses`]: https://docs.python.org/3/library/ipaddress.html#ipaddress.collapse_addresses
+    /// [`Netmask`]: struct.Netmask.html
     ///
     /// # Examples
     ///
@@ -663,50 +683,19 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// let collapsed = Ipv4Network::collapse_addresses(&[
-    ///     Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 0), 25)?,
-    ///     Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 128), 25)?,
-    /// ]);
-    ///
-    /// assert_eq!(Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 0), 24)?, collapsed[0]);
+    /// let netmask = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?.netmask_info();
+    /// assert_eq!(netmask.prefix_len(), 24);
+    /// assert_eq!(netmask.to_ipv4(), Ipv4Addr::new(255, 255, 255, 0));
+    /// assert_eq!(netmask.to_u32(), 0xffff_ff00);
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn collapse_addresses(addresses: &[Self]) -> Vec<Self> {
-        let mut subnets = HashMap::new();
-
-        let mut to_merge = addresses.to_vec();
-        while let Some(net) = to_merge.pop() {
-            let supernet = net.supernet().unwrap_or(Ipv4Network::DEFAULT_ROUTE);
-            match subnets.entry(supernet) {
-                Entry::Vacant(vacant) => {
-                    vacant.insert(net);
-                }
-                Entry::Occupied(occupied) => {
-                    if *occupied.get() != net {
-                        occupied.remove();
-                        to_merge.push(supernet);
-                    }
-                }
-            }
-        }
-
-        let mut output: Vec<Ipv4Network> = vec![];
-        let mut values = subnets.values().collect::<Vec<_>>();
-        values.sort_unstable();
-
-        for net in values {
-            if let Some(last) = output.last() {
-                // Since they are sorted, last.network_address <= net.network_address is a given.
-                if last.broadcast_address() >= net.broadcast_address() {
-                    continue;
-                }
-            }
-            output.push(*net);
-        }
-        output
+    #[inline]
+    pub const fn netmask_info(&self) -> Netmask {
+        Netmask(self.netmask)
     }
 
-    /// Converts string in format X.X.X.X/Y (CIDR notation) to `Ipv4Network`, but truncating host bits.
+    /// Returns given IP address with host bits cleared according to netmask of this network.
+    /// Same result can be obtained by `&ip_network & ip` operator.
     ///
     /// # Examples
     ///
@@ -714,26 +703,29 @@ impl Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// let ip_network = Ipv4Network::from_str_truncate("192.168.1.255/24")?;
-    /// assert_eq!(ip_network.network_address(), Ipv4Addr::new(192, 168, 1, 0));
-    /// assert_eq!(ip_network.netmask(), 24);
-    /// # Ok::<(), ip_network::IpNetworkParseError>(())
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.mask(Ipv4Addr::new(10, 0, 0, 200)), Ipv4Addr::new(10, 0, 0, 0));
+    /// assert_eq!(&ip_network & Ipv4Addr::new(10, 0, 0, 200), Ipv4Addr::new(10, 0, 0, 0));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn from_str_truncate(s: &str) -> Result<Self, IpNetworkParseError> {
-        let (ip, netmask) =
-            helpers::split_ip_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
-
-        let network_address =
-            Ipv4Addr::from_str(ip).map_err(|_| IpNetworkParseError::AddrParseError)?;
-        let netmask =
-            u8::from_str(netmask).map_err(|_| IpNetworkParseError::InvalidNetmaskFormat)?;
-
-        Self::new_truncate(network_address, netmask).map_err(IpNetworkParseError::IpNetworkError)
+    pub const fn mask(&self, ip: Ipv4Addr) -> Ipv4Addr {
+        let [a, b, c, d] =
+            (u32::from_be_bytes(ip.octets()) & helpers::bite_mask(self.netmask)).to_be_bytes();
+        Ipv4Addr::new(a, b, c, d)
     }
-}
 
-impl fmt::Display for Ipv4Network {
-    /// Converts `Ipv4Network` to string in format X.X.X.X/Y (CIDR notation).
+    /// Returns [`true`] if given [`Ipv4Addr`] or `Ipv4Network` is inside this network.
+    ///
+    /// This method is generic over [`Contained`] trait, so it is not `const fn`. In `const`
+    /// context use [`contains_address`] or [`contains_network`] instead. Because of generic
+    /// argument, values without concrete type (like result of `"10.1.2.3".parse().unwrap()`) need
+    /// type annotation.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// [`Ipv4Addr`]: https://doc.rust-lang.org/std/net/struct.Ipv4Addr.html
+    /// [`Contained`]: trait.Contained.html
+    /// [`contains_address`]: #method.contains_address
+    /// [`contains_network`]: #method.contains_network
     ///
     /// # Examples
     ///
@@ -741,74 +733,46 @@ impl fmt::Display for Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// assert_eq!(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?.to_string(), "192.168.1.0/24");
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert!(ip_network.contains(Ipv4Addr::new(192, 168, 1, 2)));
+    /// assert!(!ip_network.contains(Ipv4Addr::new(192, 168, 2, 2)));
+    /// assert!(ip_network.contains(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25)?));
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        write!(fmt, "{}/{}", self.network_address, self.netmask)
+    #[inline]
+    pub fn contains<T: Contained<Self>>(&self, other: T) -> bool {
+        other.is_contained_in(self)
     }
-}
 
-impl FromStr for Ipv4Network {
-    type Err = IpNetworkParseError;
-
-    /// Converts string in format X.X.X.X/Y (CIDR notation) to `Ipv4Network`.
+    /// Returns [`true`] if given [`Ipv4Addr`] is inside this network. Unlike [`contains`], this
+    /// method can be used in `const` context.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// [`Ipv4Addr`]: https://doc.rust-lang.org/std/net/struct.Ipv4Addr.html
+    /// [`contains`]: #method.contains
     ///
     /// # Examples
     ///
     /// ```
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
-    /// use std::str::FromStr;
     ///
-    /// let ip_network = Ipv4Network::from_str("192.168.1.0/24")?;
-    /// assert_eq!(ip_network.network_address(), Ipv4Addr::new(192, 168, 1, 0));
-    /// assert_eq!(ip_network.netmask(), 24);
-    /// # Ok::<(), ip_network::IpNetworkParseError>(())
+    /// const NETWORK: Ipv4Network = Ipv4Network::new_const(Ipv4Addr::new(192, 168, 1, 0), 24);
+    /// const CONTAINS: bool = NETWORK.contains_address(Ipv4Addr::new(192, 168, 1, 2));
+    /// assert!(CONTAINS);
     /// ```
-    fn from_str(s: &str) -> Result<Ipv4Network, IpNetworkParseError> {
-        let (ip, netmask) =
-            helpers::split_ip_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
-
-        let network_address =
-            Ipv4Addr::from_str(ip).map_err(|_| IpNetworkParseError::AddrParseError)?;
-        let netmask =
-            u8::from_str(netmask).map_err(|_| IpNetworkParseError::InvalidNetmaskFormat)?;
-
-        Self::new(network_address, netmask).map_err(IpNetworkParseError::IpNetworkError)
-    }
-}
-
-impl From<Ipv4Addr> for Ipv4Network {
-    /// Converts `Ipv4Addr` to `Ipv4Network` with netmask 32.
-    #[inline]
-    fn from(ip: Ipv4Addr) -> Self {
-        Self {
-            network_address: ip,
-            netmask: Self::LENGTH,
+    pub const fn contains_address(&self, ip: Ipv4Addr) -> bool {
+        if self.netmask == 0 {
+            return true;
         }
-    }
-}
-
-impl PartialEq for Ipv4Network {
-    #[inline]
-    fn eq(&self, other: &Ipv4Network) -> bool {
-        self.netmask == other.netmask && self.network_address == other.network_address
-    }
-}
 
-impl Hash for Ipv4Network {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.network_address.hash(state);
-        self.netmask.hash(state);
+        u32::from_be_bytes(ip.octets()) & helpers::bite_mask(self.netmask)
+            == u32::from_be_bytes(self.network_address.octets())
     }
-}
-
-impl IntoIterator for Ipv4Network {
-    type Item = Ipv4Addr;
-    type IntoIter = iterator::Ipv4RangeIterator;
 
-    /// Returns iterator over all IP addresses in range including network and broadcast addresses.
+    /// Returns [`true`] if given network is inside this network (or is the same network).
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
     ///
     /// # Examples
     ///
@@ -816,14 +780,1425 @@ impl IntoIterator for Ipv4Network {
     /// use std::net::Ipv4Addr;
     /// use ip_network::Ipv4Network;
     ///
-    /// let ip = Ipv4Addr::new(192, 168, 1, 0);
-    /// let mut iter = Ipv4Network::new(ip, 24)?.into_iter();
-    /// assert_eq!(iter.next().unwrap(), Ipv4Addr::new(192, 168, 1, 0));
-    /// assert_eq!(iter.next().unwrap(), Ipv4Addr::new(192, 168, 1, 1));
-    /// assert_eq!(iter.last().unwrap(), Ipv4Addr::new(192, 168, 1, 255));
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16)?;
+    /// assert!(ip_network.contains_network(&Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?));
+    /// assert!(!ip_network.contains_network(&Ipv4Network::new(Ipv4Addr::new(192, 0, 0, 0), 8)?));
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    fn into_iter(self) -> Self::IntoIter {
+    pub const fn contains_network(&self, other: &Ipv4Network) -> bool {
+        other.netmask >= self.netmask
+            && u32::from_be_bytes(other.network_address.octets()) & helpers::bite_mask(self.netmask)
+                == u32::from_be_bytes(self.network_address.octets())
+    }
+
+    /// Parses given string as IP address and returns [`true`] if it is inside this network.
+    /// Returns error when string is not valid IPv4 address.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network: Ipv4Network = "192.168.1.0/24".parse().unwrap();
+    /// assert_eq!(ip_network.contains_str("192.168.1.25"), Ok(true));
+    /// assert!(ip_network.contains_str("192.168.1").is_err());
+    /// ```
+    pub fn contains_str(&self, ip: &str) -> Result<bool, AddrParseError> {
+        Ipv4Addr::from_str(ip).map(|ip| self.contains_address(ip))
+    }
+
+    /// Returns iterator over host IP addresses in range (without network and broadcast address). You
+    /// can also use this method to check how much hosts address are in range by calling [`len()`] method
+    /// on iterator (see Examples).
+    ///
+    /// For netmask 31 (point-to-point links, [RFC 3021]) iterator returns both addresses and for
+    /// netmask 32 it returns the single address.
+    ///
+    /// [RFC 3021]: https://tools.ietf.org/html/rfc3021
+    /// [`len()`]: https://doc.rust-lang.org/std/iter/trait.ExactSizeIterator.html#method.len
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip = Ipv4Addr::new(192, 168, 1, 0);
+    /// let mut hosts = Ipv4Network::new(ip, 24)?.hosts();
+    /// assert_eq!(254, hosts.len());
+    /// assert_eq!(hosts.next().unwrap(), Ipv4Addr::new(192, 168, 1, 1));
+    /// assert_eq!(hosts.last().unwrap(), Ipv4Addr::new(192, 168, 1, 254));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn hosts(&self) -> iterator::Ipv4RangeIterator {
+        iterator::Ipv4RangeIterator::hosts(*self)
+    }
+
+    /// Returns iterator over host IP addresses in range (without network and broadcast address)
+    /// grouped to vectors with `chunk_size` addresses. The last vector can be shorter.
+    ///
+    /// # Panics
+    ///
+    /// This method panics when `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 29)?;
+    /// let mut chunks = ip_network.hosts_chunked(4);
+    /// assert_eq!(4, chunks.next().unwrap().len());
+    /// assert_eq!(chunks.next().unwrap(), vec![Ipv4Addr::new(192, 168, 1, 5), Ipv4Addr::new(192, 168, 1, 6)]);
+    /// assert!(chunks.next().is_none());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn hosts_chunked(&self, chunk_size: usize) -> impl Iterator<Item = Vec<Ipv4Addr>> {
+        assert!(chunk_size != 0);
+
+        let mut hosts = self.hosts();
+        iter::from_fn(move || {
+            let chunk = hosts.by_ref().take(chunk_size).collect::<Vec<_>>();
+            if chunk.is_empty() {
+                None
+            } else {
+                Some(chunk)
+            }
+        })
+    }
+
+    /// Returns iterator over every `step`-th IP address in network, starting with network address
+    /// (`network_address`, `network_address + step`, ...) up to broadcast address. When `step`
+    /// is zero, only network address is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// let mut addresses = ip_network.addresses_stride(100);
+    /// assert_eq!(addresses.next(), Some(Ipv4Addr::new(192, 168, 1, 0)));
+    /// assert_eq!(addresses.next(), Some(Ipv4Addr::new(192, 168, 1, 100)));
+    /// assert_eq!(addresses.next(), Some(Ipv4Addr::new(192, 168, 1, 200)));
+    /// assert_eq!(addresses.next(), None);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn addresses_stride(&self, step: u32) -> impl Iterator<Item = Ipv4Addr> {
+        let first = u64::from(u32::from(self.network_address));
+        let last = u64::from(u32::from(self.broadcast_address()));
+        let step = u64::from(step);
+
+        iter::successors(Some(first), move |&address| {
+            if step == 0 {
+                None
+            } else {
+                Some(address + step).filter(|&address| address <= last)
+            }
+        })
+        .map(|address| Ipv4Addr::from(address as u32))
+    }
+
+    /// Returns iterator over IP addresses that are part of both networks. When one network
+    /// contains the other, returns all addresses of the more specific network, including its
+    /// network and broadcast address. When networks are disjoint, iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// let other = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 4), 30)?;
+    /// let mut addresses = ip_network.intersection_addresses(&other);
+    /// assert_eq!(addresses.next(), Some(Ipv4Addr::new(192, 168, 1, 4)));
+    /// assert_eq!(addresses.last(), Some(Ipv4Addr::new(192, 168, 1, 7)));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn intersection_addresses(&self, other: &Ipv4Network) -> impl Iterator<Item = Ipv4Addr> {
+        let intersection = if self.contains_network(other) {
+            Some(*other)
+        } else if other.contains_network(self) {
+            Some(*self)
+        } else {
+            None
+        };
+
+        intersection.into_iter().flatten()
+    }
+
+    /// Returns network with smaller netmask by one. If netmask is already zero, `None` will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip = Ipv4Addr::new(192, 168, 1, 0);
+    /// let mut hosts = Ipv4Network::new(ip, 24)?;
+    /// assert_eq!(hosts.supernet(), Some(Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 23)?));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn supernet(&self) -> Option<Self> {
+        if self.netmask == 0 {
+            None
+        } else {
+            Some(Self::new_truncate(self.network_address, self.netmask - 1).unwrap())
+        }
+    }
+
+    /// Returns network with netmask changed by `delta`. Positive `delta` shortens netmask (returns
+    /// supernet), negative `delta` lengthens netmask and returns the first subnet, that has the
+    /// same network address as this network. Returns `None` when new netmask would be out of
+    /// range 0 to 32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.adjust_prefix(2), Some(Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 22)?));
+    /// assert_eq!(ip_network.adjust_prefix(-2), Some(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 26)?));
+    /// assert_eq!(ip_network.adjust_prefix(-9), None);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn adjust_prefix(&self, delta: i8) -> Option<Self> {
+        let netmask = i16::from(self.netmask) - i16::from(delta);
+        if netmask < 0 || netmask > i16::from(Self::LENGTH) {
+            return None;
+        }
+
+        Some(Self::new_truncate(self.network_address, netmask as u8).unwrap())
+    }
+
+    /// Returns following network with the same netmask. This method wraps around, so after the
+    /// last network in address space (for example 255.255.255.0/24) the first one (0.0.0.0/24) is
+    /// returned. Network with netmask 0 returns itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// assert_eq!(ip_network.next_wrapping(), Ipv4Network::new(Ipv4Addr::new(192, 168, 2, 0), 24)?);
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 0), 24)?;
+    /// assert_eq!(ip_network.next_wrapping(), Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 24)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn next_wrapping(&self) -> Self {
+        match 1u32.checked_shl(u32::from(Self::LENGTH - self.netmask)) {
+            Some(step) => Self {
+                network_address: Ipv4Addr::from(u32::from(self.network_address).wrapping_add(step)),
+                netmask: self.netmask,
+            },
+            None => *self,
+        }
+    }
+
+    /// Returns iterator over all supernets of this network, from network with netmask smaller by
+    /// one to network with netmask 0 (including). This is the same as repeatedly calling
+    /// [`supernet`] method.
+    ///
+    /// [`supernet`]: #method.supernet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// let mut iterator = ip_network.supernets();
+    /// assert_eq!(iterator.next().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 23)?);
+    /// assert_eq!(iterator.next().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 22)?);
+    /// assert_eq!(iterator.last().unwrap(), Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 0)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn supernets(&self) -> impl ExactSizeIterator<Item = Ipv4Network> {
+        let network_address = self.network_address;
+        (0..self.netmask)
+            .rev()
+            .map(move |netmask| Self::new_truncate(network_address, netmask).unwrap())
+    }
+
+    /// Returns iterator over all networks that contains this network, from network with netmask 0
+    /// to this network itself (including).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// let mut iterator = ip_network.enclosing_prefixes();
+    /// assert_eq!(iterator.next().unwrap(), Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 0)?);
+    /// assert_eq!(iterator.next().unwrap(), Ipv4Network::new(Ipv4Addr::new(128, 0, 0, 0), 1)?);
+    /// assert_eq!(iterator.last().unwrap(), ip_network);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn enclosing_prefixes(&self) -> impl ExactSizeIterator<Item = Ipv4Network> {
+        let network_address = self.network_address;
+        (0..=self.netmask).map(move |netmask| Self::new_truncate(network_address, netmask).unwrap())
+    }
+
+    /// Returns the most specific network from `candidates` that contains this network (or is
+    /// equal to it), or `None` when no candidate contains this network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let candidates = [
+    ///     Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8)?,
+    ///     Ipv4Network::new(Ipv4Addr::new(10, 1, 0, 0), 16)?,
+    /// ];
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 1, 2, 0), 24)?;
+    /// assert_eq!(ip_network.enclosing_in(&candidates), Some(candidates[1]));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn enclosing_in(&self, candidates: &[Ipv4Network]) -> Option<Ipv4Network> {
+        candidates
+            .iter()
+            .filter(|candidate| candidate.contains_network(self))
+            .max_by_key(|candidate| candidate.netmask)
+            .copied()
+    }
+
+    /// Returns iterator over networks with bigger netmask by one. If netmask is already 32,
+    /// iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// let mut iterator = ip_network.subnets();
+    /// assert_eq!(iterator.next().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 25)?);
+    /// assert_eq!(iterator.last().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn subnets(&self) -> impl ExactSizeIterator<Item = Ipv4Network> + DoubleEndedIterator {
+        let new_netmask = cmp::min(self.netmask + 1, Self::LENGTH);
+        iterator::Ipv4NetworkIterator::new(*self, new_netmask)
+    }
+
+    /// Returns two subnets with netmask bigger by one, that together form this network. For
+    /// network with netmask 32 returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// let (first, second) = ip_network.halves().unwrap();
+    /// assert_eq!(first, Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 25)?);
+    /// assert_eq!(second, Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn halves(&self) -> Option<(Self, Self)> {
+        if self.netmask == Self::LENGTH {
+            return None;
+        }
+
+        let netmask = self.netmask + 1;
+        let second = u32::from(self.network_address) | (1 << (Self::LENGTH - netmask));
+        Some((
+            Self {
+                network_address: self.network_address,
+                netmask,
+            },
+            Self {
+                network_address: Ipv4Addr::from(second),
+                netmask,
+            },
+        ))
+    }
+
+    /// Returns the other half of [`supernet`], network with the same netmask that shares parent
+    /// network with this network. For network with netmask 0 returns `None`.
+    ///
+    /// [`supernet`]: #method.supernet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 25)?;
+    /// assert_eq!(ip_network.sibling(), Some(Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 128), 25)?));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn sibling(&self) -> Option<Self> {
+        if self.netmask == 0 {
+            return None;
+        }
+
+        let network_address =
+            u32::from(self.network_address) ^ (1 << (Self::LENGTH - self.netmask));
+        Some(Self {
+            network_address: Ipv4Addr::from(network_address),
+            netmask: self.netmask,
+        })
+    }
+
+    /// Returns `Ipv4NetworkIterator` over networks with defined netmask.
+    ///
+    /// # Panics
+    ///
+    /// This method panics when prefix is bigger than 32 or when prefix is lower or equal than netmask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip = Ipv4Addr::new(192, 168, 1, 0);
+    /// let mut iterator = Ipv4Network::new(ip, 24)?.subnets_with_prefix(25);
+    /// assert_eq!(iterator.next().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 25)?);
+    /// assert_eq!(iterator.last().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn subnets_with_prefix(
+        &self,
+        prefix: u8,
+    ) -> impl ExactSizeIterator<Item = Ipv4Network> + DoubleEndedIterator {
+        iterator::Ipv4NetworkIterator::new(*self, prefix)
+    }
+
+    /// Recursively subdivides this network to networks with netmask bigger by one, until `prefix`
+    /// netmask is reached, and calls `f` for every network in pre-order (each network is visited
+    /// before its subnets). This network itself is visited first.
+    ///
+    /// # Panics
+    ///
+    /// This method panics when prefix is bigger than 32 or when prefix is lower than netmask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 23)?;
+    /// let mut visited = vec![];
+    /// ip_network.for_each_subnet_down_to(24, |network| visited.push(network));
+    /// assert_eq!(visited, vec![
+    ///     ip_network,
+    ///     Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 24)?,
+    ///     Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?,
+    /// ]);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn for_each_subnet_down_to<F: FnMut(Ipv4Network)>(&self, prefix: u8, mut f: F) {
+        fn walk<F: FnMut(Ipv4Network)>(network: Ipv4Network, prefix: u8, f: &mut F) {
+            f(network);
+            if network.netmask < prefix {
+                for subnet in network.subnets() {
+                    walk(subnet, prefix, f);
+                }
+            }
+        }
+
+        assert!(prefix <= Self::LENGTH);
+        assert!(prefix >= self.netmask);
+
+        walk(*self, prefix, &mut f);
+    }
+
+    /// Returns vector of all networks with defined netmask, that are part of this network.
+    ///
+    /// Unlike [`subnets_with_prefix`], this method returns error instead of panic when prefix is
+    /// bigger than 32 or when prefix is lower or equal than netmask.
+    ///
+    /// [`subnets_with_prefix`]: #method.subnets_with_prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::{IpNetworkError, Ipv4Network};
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// let subnets = ip_network.partition(26)?;
+    /// assert_eq!(4, subnets.len());
+    /// assert_eq!(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 192), 26)?, subnets[3]);
+    /// assert_eq!(Err(IpNetworkError::NetmaskError(24)), ip_network.partition(24));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn partition(&self, prefix: u8) -> Result<Vec<Self>, IpNetworkError> {
+        if prefix > Self::LENGTH || prefix <= self.netmask {
+            return Err(IpNetworkError::NetmaskError(prefix));
+        }
+
+        // `ExactSizeIterator` allows to allocate whole vector at once
+        Ok(self.subnets_with_prefix(prefix).collect())
+    }
+
+    /// Carves non overlapping subnets from this network, one for each required host count
+    /// (variable length subnet masking). Each subnet is the smallest network with at least
+    /// required number of hosts, counted the same way as by [`hosts`] method. So one host gets
+    /// network with netmask 32 and two hosts network with netmask 31 ([RFC 3021]), bigger networks
+    /// don't count network and broadcast address. Subnets are allocated from network address,
+    /// largest first, and returned in the same order as `host_counts`.
+    ///
+    /// Returns [`NotEnoughAddresses`] with host count of the first subnet (in allocation order)
+    /// that doesn't fit into this network.
+    ///
+    /// [`hosts`]: #method.hosts
+    /// [RFC 3021]: https://tools.ietf.org/html/rfc3021
+    /// [`NotEnoughAddresses`]: enum.IpNetworkError.html#variant.NotEnoughAddresses
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::{IpNetworkError, Ipv4Network};
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 24)?;
+    /// let subnets = ip_network.vlsm(&[20, 100])?;
+    /// assert_eq!(Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 128), 27)?, subnets[0]);
+    /// assert_eq!(Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 25)?, subnets[1]);
+    /// assert_eq!(Err(IpNetworkError::NotEnoughAddresses(20)), ip_network.vlsm(&[200, 20]));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn vlsm(&self, host_counts: &[u32]) -> Result<Vec<Self>, IpNetworkError> {
+        let prefixes = host_counts
+            .iter()
+            .map(|&count| {
+                let bits = match count {
+                    0 | 1 => 0,
+                    2 => 1,
+                    // Two more addresses for network and broadcast address
+                    _ => (u64::from(count) + 2).next_power_of_two().trailing_zeros(),
+                };
+                if bits > u32::from(Self::LENGTH) {
+                    Err(IpNetworkError::NotEnoughAddresses(count))
+                } else {
+                    Ok(Self::LENGTH - bits as u8)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Allocating largest subnets first keeps all subnets aligned
+        let mut order = (0..prefixes.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&index| prefixes[index]);
+
+        let first = u64::from(u32::from(self.network_address));
+        let size = 1u64 << (Self::LENGTH - self.netmask);
+        let mut offset = 0;
+        let mut subnets = vec![*self; prefixes.len()];
+        for index in order {
+            let prefix = prefixes[index];
+            let subnet_size = 1u64 << (Self::LENGTH - prefix);
+            if prefix < self.netmask || offset + subnet_size > size {
+                return Err(IpNetworkError::NotEnoughAddresses(host_counts[index]));
+            }
+            subnets[index] = Self {
+                network_address: Ipv4Addr::from((first + offset) as u32),
+                netmask: prefix,
+            };
+            offset += subnet_size;
+        }
+
+        Ok(subnets)
+    }
+
+    /// Returns [`true`] for the default route network (0.0.0.0/0), that contains all IPv4 addresses.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 0)?.is_default_route());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub const fn is_default_route(&self) -> bool {
+        self.netmask == 0
+    }
+
+    /// Returns [`true`] for the default route network (0.0.0.0/0), same as [`is_default_route`].
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// [`is_default_route`]: #method.is_default_route
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 0)?.is_default());
+    /// assert!(!Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 8)?.is_default());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub const fn is_default(&self) -> bool {
+        self.is_default_route()
+    }
+
+    /// Returns [`true`] for network in local identification range (0.0.0.0/8).
+    ///
+    /// This property is defined by [IETF RFC 1122].
+    ///
+    /// [IETF RFC 1122]: https://tools.ietf.org/html/rfc1122
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 8)?.is_local_identification());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_local_identification(&self) -> bool {
+        self.network_address.octets()[0] == 0 && self.netmask >= 8
+    }
+
+    /// Returns [`true`] for the special 'unspecified' network (0.0.0.0/32).
+    ///
+    /// This property is defined in _UNIX Network Programming, Second Edition_,
+    /// W. Richard Stevens, p. 891; see also [ip7].
+    ///
+    /// [ip7]: http://man7.org/linux/man-pages/man7/ip.7.html
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 32)?.is_unspecified());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_unspecified(&self) -> bool {
+        self.netmask == Self::LENGTH && self.network_address.is_unspecified()
+    }
+
+    /// Returns [`true`] if this network is inside loopback address range (127.0.0.0/8).
+    ///
+    /// This property is defined by [IETF RFC 1122].
+    ///
+    /// [IETF RFC 1122]: https://tools.ietf.org/html/rfc1122
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 0), 8)?.is_loopback());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_loopback(&self) -> bool {
+        self.network_address.is_loopback()
+    }
+
+    /// Returns [`true`] if this is a broadcast network (255.255.255.255/32).
+    ///
+    /// A broadcast address has all octets set to 255 as defined in [IETF RFC 919].
+    ///
+    /// [IETF RFC 919]: https://tools.ietf.org/html/rfc919
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 255), 32)?.is_broadcast());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_broadcast(&self) -> bool {
+        self.network_address.is_broadcast()
+    }
+
+    /// Returns [`true`] if this whole network range is inside private address ranges.
+    ///
+    /// The private address ranges are defined in [IETF RFC 1918] and include:
+    ///
+    ///  - 10.0.0.0/8
+    ///  - 172.16.0.0/12
+    ///  - 192.168.0.0/16
+    ///
+    /// [IETF RFC 1918]: https://tools.ietf.org/html/rfc1918
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?.is_private());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_private(&self) -> bool {
+        let octets = self.network_address.octets();
+        match octets[0] {
+            10 if self.netmask >= 8 => true,
+            172 if octets[1] >= 16 && octets[1] <= 31 && self.netmask >= 12 => true,
+            192 if octets[1] == 168 && self.netmask >= 16 => true,
+            _ => false,
+        }
+    }
+
+    /// Returns [`true`] if this whole network is inside IETF Protocol Assignments range (192.0.0.0/24).
+    ///
+    /// This property is defined by [IETF RFC 6890, Section 2.1].
+    ///
+    /// [IETF RFC 6890, Section 2.1]: https://tools.ietf.org/html/rfc6890#section-2.1
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(192, 0, 0, 0), 24)?.is_ietf_protocol_assignments());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_ietf_protocol_assignments(&self) -> bool {
+        let octets = self.network_address.octets();
+        octets[0] == 192 && octets[1] == 0 && octets[2] == 0 && self.netmask >= 24
+    }
+
+    /// Returns [`true`] if this whole network is inside Shared Address Space (100.64.0.0/10).
+    ///
+    /// This property is defined by [IETF RFC 6598].
+    ///
+    /// [IETF RFC 6598]: https://tools.ietf.org/html/rfc6598
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(100, 64, 0, 0), 10)?.is_shared_address_space());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_shared_address_space(&self) -> bool {
+        let octets = self.network_address.octets();
+        octets[0] == 100 && octets[1] & 0xc0 == 64
+    }
+
+    /// Returns [`true`] if the network is is inside link-local range (169.254.0.0/16).
+    ///
+    /// This property is defined by [IETF RFC 3927].
+    ///
+    /// [IETF RFC 3927]: https://tools.ietf.org/html/rfc3927
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(169, 254, 1, 0), 24)?.is_link_local());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_link_local(&self) -> bool {
+        self.network_address.is_link_local() && self.netmask >= 16
+    }
+
+    /// Returns [`true`] if this whole network is inside multicast address range (224.0.0.0/4).
+    ///
+    /// Multicast network addresses have a most significant octet between 224 and 239,
+    /// and is defined by [IETF RFC 5771].
+    ///
+    /// [IETF RFC 5771]: https://tools.ietf.org/html/rfc5771
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(224, 168, 1, 0), 24)?.is_multicast());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_multicast(&self) -> bool {
+        self.network_address.octets()[0] & 0xf0 == 224 && self.netmask >= 4
+    }
+
+    /// Returns [`true`] if this whole network is inside benchmarking address range (198.18.0.0/15).
+    ///
+    /// This property is defined by [IETF RFC 2544].
+    ///
+    /// [IETF RFC 2544]: https://tools.ietf.org/html/rfc2544
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(198, 19, 1, 0), 24)?.is_benchmarking());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_benchmarking(&self) -> bool {
+        // Not necessary to check netmask
+        let octets = self.network_address.octets();
+        octets[0] == 198 && octets[1] & 0xfe == 18
+    }
+
+    /// Returns [`true`] if this whole network is inside reserved address range (240.0.0.0/4), except
+    /// broadcast address (255.255.255.255/32).
+    ///
+    /// Reserved network addresses have a most significant octet between 240 and 255,
+    /// and is defined by [IETF RFC 1112].
+    ///
+    /// [IETF RFC 1112]: https://tools.ietf.org/html/rfc1112
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(240, 168, 1, 0), 24)?.is_reserved());
+    /// assert!(!Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 255), 32)?.is_reserved());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_reserved(&self) -> bool {
+        // Not necessary to check netmask
+        self.network_address.octets()[0] & 0xf0 == 240 && !self.network_address.is_broadcast()
+    }
+
+    /// Returns [`true`] if this network is in a range designated for documentation.
+    ///
+    /// This is defined in [IETF RFC 5737]:
+    ///
+    /// - 192.0.2.0/24 (TEST-NET-1)
+    /// - 198.51.100.0/24 (TEST-NET-2)
+    /// - 203.0.113.0/24 (TEST-NET-3)
+    ///
+    /// [IETF RFC 5737]: https://tools.ietf.org/html/rfc5737
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 0), 24)?.is_documentation());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_documentation(&self) -> bool {
+        self.network_address.is_documentation() && self.netmask >= 24
+    }
+
+    /// Returns [`true`] if this network is in a range used by AS112 DNS anycast servers.
+    ///
+    /// These ranges are defined in [IETF RFC 7534] and [IETF RFC 7535]:
+    ///
+    /// - 192.175.48.0/24 (Direct Delegation AS112 Service)
+    /// - 192.31.196.0/24 (AS112-v4, DNAME redirection)
+    ///
+    /// [IETF RFC 7534]: https://tools.ietf.org/html/rfc7534
+    /// [IETF RFC 7535]: https://tools.ietf.org/html/rfc7535
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(192, 175, 48, 0), 24)?.is_as112());
+    /// assert!(!Ipv4Network::new(Ipv4Addr::new(192, 175, 0, 0), 16)?.is_as112());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_as112(&self) -> bool {
+        let octets = self.network_address.octets();
+        self.netmask >= 24 && matches!(octets, [192, 175, 48, _] | [192, 31, 196, _])
+    }
+
+    /// Returns [`true`] if the network appears to be globally routable.
+    /// See [IANA IPv4 Special-Purpose Address Registry][ipv4-sr].
+    ///
+    /// The following return [`false`]:
+    ///
+    /// - local identification (0.0.0.0/8)
+    /// - private address (10.0.0.0/8, 172.16.0.0/12 and 192.168.0.0/16)
+    /// - Shared Address Space (100.64.0.0/10)
+    /// - the loopback address (127.0.0.0/8)
+    /// - the link-local address (169.254.0.0/16)
+    /// - IETF Protocol Assignments (192.0.0.0/24, except 192.0.0.9/32 and 192.0.0.10/32)
+    /// - the broadcast address (255.255.255.255/32)
+    /// - test addresses used for documentation (192.0.2.0/24, 198.51.100.0/24 and 203.0.113.0/24)
+    /// - benchmarking (198.18.0.0/15)
+    /// - reserved range (240.0.0.0/4)
+    ///
+    /// [ipv4-sr]: https://www.iana.org/assignments/iana-ipv4-special-registry/iana-ipv4-special-registry.xhtml
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// [`false`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert!(!Ipv4Network::new(Ipv4Addr::new(10, 254, 0, 0), 16)?.is_global());
+    /// assert!(!Ipv4Network::new(Ipv4Addr::new(192, 168, 10, 65), 32)?.is_global());
+    /// assert!(!Ipv4Network::new(Ipv4Addr::new(172, 16, 10, 65), 32)?.is_global());
+    /// assert!(!Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 32)?.is_global());
+    /// assert!(Ipv4Network::new(Ipv4Addr::new(80, 9, 12, 3), 32)?.is_global());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_global(&self) -> bool {
+        let octets = self.network_address.octets();
+        // These address are only two globally routable from IETF Protocol Assignments.
+        if self.netmask == 32 && (octets == [192, 0, 0, 9] || octets == [192, 0, 0, 10]) {
+            return true;
+        }
+
+        !self.is_local_identification()
+            && !self.is_private()
+            && !self.is_ietf_protocol_assignments()
+            && !self.is_shared_address_space()
+            && !self.is_loopback()
+            && !self.is_link_local()
+            && !self.is_broadcast()
+            && !self.is_documentation()
+            && !self.is_benchmarking()
+            && !self.is_reserved()
+    }
+
+    /// Returns name of block from [IANA IPv4 Special-Purpose Address Registry][ipv4-sr] that
+    /// contains this whole network, or `None` when network is not part of any special-purpose
+    /// block known to this crate.
+    ///
+    /// [ipv4-sr]: https://www.iana.org/assignments/iana-ipv4-special-registry/iana-ipv4-special-registry.xhtml
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert_eq!(Some("Private-Use"), Ipv4Network::new(Ipv4Addr::new(10, 1, 0, 0), 16)?.registry_name());
+    /// assert_eq!(Some("Loopback"), Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 1), 32)?.registry_name());
+    /// assert_eq!(None, Ipv4Network::new(Ipv4Addr::new(80, 9, 12, 0), 24)?.registry_name());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn registry_name(&self) -> Option<&'static str> {
+        let name = if self.is_local_identification() {
+            "This network"
+        } else if self.is_private() {
+            "Private-Use"
+        } else if self.is_shared_address_space() {
+            "Shared Address Space"
+        } else if self.is_loopback() {
+            "Loopback"
+        } else if self.is_link_local() {
+            "Link Local"
+        } else if self.is_ietf_protocol_assignments() {
+            "IETF Protocol Assignments"
+        } else if self.is_documentation() {
+            "Documentation (RFC 5737)"
+        } else if self.is_benchmarking() {
+            "Benchmarking"
+        } else if self.is_as112() {
+            "AS112"
+        } else if self.is_broadcast() {
+            "Limited Broadcast"
+        } else if self.is_reserved() {
+            "Reserved"
+        } else {
+            return None;
+        };
+        Some(name)
+    }
+
+    /// Return a vector of the summarized network range given the first and last IPv4 addresses.
+    /// Implementation of this method was inspired by Python [`ipaddress.summarize_address_range`]
+    /// method. If first IP address is bigger than last, empty vector is returned.
+    ///
+    /// [`ipaddress.summarize_address_range`]: https://docs.python.org/3/library/ipaddress.html#ipaddress.summarize_address_range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ranges = Ipv4Network::summarize_address_range(
+    ///     Ipv4Addr::new(10, 254, 0, 0),
+    ///     Ipv4Addr::new(10, 255, 255, 255),
+    /// );
+    ///
+    /// assert_eq!(Ipv4Network::new(Ipv4Addr::new(10, 254, 0, 0), 15)?, ranges[0]);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn summarize_address_range(first: Ipv4Addr, last: Ipv4Addr) -> Vec<Self> {
+        let mut first_int = u32::from(first);
+        let last_int = u32::from(last);
+
+        let mut vector = vec![];
+
+        while first_int <= last_int {
+            let bit_length_diff = if last_int - first_int == u32::MAX {
+                Self::LENGTH
+            } else {
+                helpers::bit_length(last_int - first_int + 1) - 1
+            };
+
+            let nbits = cmp::min(first_int.trailing_zeros() as u8, bit_length_diff);
+
+            vector.push(Self {
+                network_address: Ipv4Addr::from(first_int),
+                netmask: Self::LENGTH - nbits,
+            });
+
+            if nbits == Self::LENGTH {
+                break;
+            }
+
+            match first_int.checked_add(1 << nbits) {
+                Some(x) => first_int = x,
+                None => break,
+            }
+        }
+
+        vector
+    }
+
+    /// Return an iterator of the collapsed Ipv4Networks.
+    ///
+    /// Returned networks are sorted by network address and netmask and result doesn't depend on
+    /// order of given networks.
+    ///
+    /// Implementation of this method was inspired by Python [`ipaddress.collapse_addresses`]
+    ///
+    /// [`ipaddress.collapse_addresses`]: https://docs.python.org/3/library/ipaddress.html#ipaddress.collapse_addresses
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let collapsed = Ipv4Network::collapse_addresses(&[
+    ///     Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 0), 25)?,
+    ///     Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 128), 25)?,
+    /// ]);
+    ///
+    /// assert_eq!(Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 0), 24)?, collapsed[0]);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn collapse_addresses(addresses: &[Self]) -> Vec<Self> {
+        let mut subnets = BTreeMap::new();
+
+        let mut to_merge = addresses.to_vec();
+        while let Some(net) = to_merge.pop() {
+            let supernet = net.supernet().unwrap_or(Ipv4Network::DEFAULT_ROUTE);
+            match subnets.entry(supernet) {
+                Entry::Vacant(vacant) => {
+                    vacant.insert(net);
+                }
+                Entry::Occupied(occupied) => {
+                    if *occupied.get() != net {
+                        occupied.remove();
+                        to_merge.push(supernet);
+                    }
+                }
+            }
+        }
+
+        let mut output: Vec<Ipv4Network> = vec![];
+        let mut values = subnets.values().collect::<Vec<_>>();
+        values.sort_unstable();
+
+        for net in values {
+            if let Some(last) = output.last() {
+                // Since they are sorted, last.network_address <= net.network_address is a given.
+                if last.broadcast_address() >= net.broadcast_address() {
+                    continue;
+                }
+            }
+            output.push(*net);
+        }
+        output
+    }
+
+    /// Collapses networks same as [`collapse_addresses`], but keeps metadata value for every
+    /// network. When two networks are merged (adjacent networks are merged to their supernet or
+    /// network is contained in another network), their metadata are merged by `combine` function.
+    ///
+    /// Returned networks are sorted by network address.
+    ///
+    /// [`collapse_addresses`]: #method.collapse_addresses
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let aggregated = Ipv4Network::aggregate_with_metadata(
+    ///     vec![
+    ///         (Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 0), 25)?, 10),
+    ///         (Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 128), 25)?, 5),
+    ///     ],
+    ///     |a, b| a + b,
+    /// );
+    ///
+    /// assert_eq!(vec![(Ipv4Network::new(Ipv4Addr::new(192, 0, 2, 0), 24)?, 15)], aggregated);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn aggregate_with_metadata<M>(
+        mut entries: Vec<(Self, M)>,
+        combine: impl Fn(M, M) -> M,
+    ) -> Vec<(Self, M)> {
+        // Bigger networks are sorted before their subnets
+        entries.sort_by_key(|(network, _)| *network);
+
+        let mut output: Vec<(Self, M)> = Vec::with_capacity(entries.len());
+        for (network, metadata) in entries {
+            if let Some((last, _)) = output.last() {
+                if last.contains_network(&network) {
+                    let (last, last_metadata) = output.pop().unwrap();
+                    output.push((last, combine(last_metadata, metadata)));
+                    continue;
+                }
+            }
+
+            output.push((network, metadata));
+
+            // Merge adjacent networks with the same supernet
+            while output.len() >= 2 {
+                let (b, _) = output[output.len() - 1];
+                let (a, _) = output[output.len() - 2];
+                match a.supernet() {
+                    Some(supernet) if a.netmask == b.netmask && supernet.contains_network(&b) => {
+                        let (_, b_metadata) = output.pop().unwrap();
+                        let (_, a_metadata) = output.pop().unwrap();
+                        output.push((supernet, combine(a_metadata, b_metadata)));
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Returns minimal list of networks covering this network without given allocated networks,
+    /// sorted by network address. Allocated networks that are outside of this network are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// let free = ip_network.free_subnets(&[Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 25)?]);
+    /// assert_eq!(free, vec![Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25)?]);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn free_subnets(&self, allocated: &[Self]) -> Vec<Self> {
+        if allocated
+            .iter()
+            .any(|network| network.contains_network(self))
+        {
+            return vec![];
+        }
+
+        let allocated = allocated
+            .iter()
+            .filter(|network| self.contains_network(network))
+            .copied()
+            .collect::<Vec<_>>();
+
+        let mut output = vec![];
+        let mut first = u64::from(u32::from(self.network_address));
+        for network in Self::collapse_addresses(&allocated) {
+            let network_first = u64::from(u32::from(network.network_address));
+            if network_first > first {
+                output.extend(Self::summarize_address_range(
+                    Ipv4Addr::from(first as u32),
+                    Ipv4Addr::from((network_first - 1) as u32),
+                ));
+            }
+            first = u64::from(u32::from(network.broadcast_address())) + 1;
+        }
+
+        let last = u64::from(u32::from(self.broadcast_address()));
+        if first <= last {
+            output.extend(Self::summarize_address_range(
+                Ipv4Addr::from(first as u32),
+                Ipv4Addr::from(last as u32),
+            ));
+        }
+
+        output
+    }
+
+    /// Splits network around given IP address. Returns minimal list of networks covering range
+    /// before address (`[network_address, ip)`) and minimal list of networks covering range
+    /// after address (`(ip, broadcast_address]`). Given address itself is not part of any returned
+    /// network. If address is not inside this network, `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?;
+    /// let (before, after) = ip_network.split_around(Ipv4Addr::new(192, 168, 1, 255)).unwrap();
+    /// assert_eq!(before, vec![Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 25)?,
+    ///                         Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 26)?,
+    ///                         Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 192), 27)?,
+    ///                         Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 224), 28)?,
+    ///                         Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 240), 29)?,
+    ///                         Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 248), 30)?,
+    ///                         Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 252), 31)?,
+    ///                         Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 254), 32)?]);
+    /// assert!(after.is_empty());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn split_around(&self, ip: Ipv4Addr) -> Option<(Vec<Self>, Vec<Self>)> {
+        if !self.contains(ip) {
+            return None;
+        }
+
+        let ip = u32::from(ip);
+
+        let before = if ip > u32::from(self.network_address) {
+            Self::summarize_address_range(self.network_address, Ipv4Addr::from(ip - 1))
+        } else {
+            vec![]
+        };
+
+        let after = if ip < u32::from(self.broadcast_address()) {
+            Self::summarize_address_range(Ipv4Addr::from(ip + 1), self.broadcast_address())
+        } else {
+            vec![]
+        };
+
+        Some((before, after))
+    }
+
+    /// Converts string in format X.X.X.X/Y (CIDR notation) or X.X.X.X/M.M.M.M (with dotted network
+    /// mask) to `Ipv4Network`, but truncating host bits. Plain IP address without netmask is
+    /// converted to network with netmask 32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::from_str_truncate("192.168.1.255/24")?;
+    /// assert_eq!(ip_network.network_address(), Ipv4Addr::new(192, 168, 1, 0));
+    /// assert_eq!(ip_network.netmask(), 24);
+    /// # Ok::<(), ip_network::IpNetworkParseError>(())
+    /// ```
+    pub fn from_str_truncate(s: &str) -> Result<Self, IpNetworkParseError> {
+        let (ip, netmask) =
+            helpers::split_ip_optional_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
+
+        let network_address =
+            Ipv4Addr::from_str(ip).map_err(|_| helpers::ipv4_addr_parse_error(ip))?;
+        let netmask = match netmask {
+            Some(netmask) => helpers::parse_ipv4_netmask(netmask, ip.len() + 1)?,
+            None => Self::LENGTH,
+        };
+
+        Self::new_truncate(network_address, netmask).map_err(IpNetworkParseError::IpNetworkError)
+    }
+}
+
+impl fmt::Display for Ipv4Network {
+    /// Converts `Ipv4Network` to string in format X.X.X.X/Y (CIDR notation).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// assert_eq!(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?.to_string(), "192.168.1.0/24");
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if fmt.width().is_none() && fmt.precision().is_none() {
+            write!(fmt, "{}/{}", self.network_address, self.netmask)
+        } else {
+            // Longest possible output is `255.255.255.255/32`
+            let mut buffer = helpers::FixedBuffer::<18>::new();
+            self.write_cidr(&mut buffer)?;
+            fmt.pad(buffer.as_str())
+        }
+    }
+}
+
+impl FromStr for Ipv4Network {
+    type Err = IpNetworkParseError;
+
+    /// Converts string in format X.X.X.X/Y (CIDR notation) or X.X.X.X/M.M.M.M (with dotted network
+    /// mask) to `Ipv4Network`. Dotted network mask must have contiguous bits. Plain IP address
+    /// without netmask is converted to network with netmask 32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    /// use std::str::FromStr;
+    ///
+    /// let ip_network = Ipv4Network::from_str("192.168.1.0/24")?;
+    /// assert_eq!(ip_network.network_address(), Ipv4Addr::new(192, 168, 1, 0));
+    /// assert_eq!(ip_network.netmask(), 24);
+    /// assert_eq!(ip_network, Ipv4Network::from_str("192.168.1.0/255.255.255.0")?);
+    /// assert_eq!(Ipv4Network::from_str("192.168.1.1")?.netmask(), 32);
+    /// # Ok::<(), ip_network::IpNetworkParseError>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Ipv4Network, IpNetworkParseError> {
+        let (ip, netmask) =
+            helpers::split_ip_optional_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
+
+        let network_address =
+            Ipv4Addr::from_str(ip).map_err(|_| helpers::ipv4_addr_parse_error(ip))?;
+        let netmask = match netmask {
+            Some(netmask) => helpers::parse_ipv4_netmask(netmask, ip.len() + 1)?,
+            None => Self::LENGTH,
+        };
+
+        Self::new(network_address, netmask).map_err(IpNetworkParseError::IpNetworkError)
+    }
+}
+
+impl From<Ipv4Addr> for Ipv4Network {
+    /// Converts `Ipv4Addr` to `Ipv4Network` with netmask 32.
+    #[inline]
+    fn from(ip: Ipv4Addr) -> Self {
+        Self {
+            network_address: ip,
+            netmask: Self::LENGTH,
+        }
+    }
+}
+
+impl PartialEq for Ipv4Network {
+    #[inline]
+    fn eq(&self, other: &Ipv4Network) -> bool {
+        self.netmask == other.netmask && self.network_address == other.network_address
+    }
+}
+
+impl PartialEq<Ipv4Addr> for Ipv4Network {
+    /// Returns [`true`] only when network has netmask 32 and its address is the same as `other`.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    #[inline]
+    fn eq(&self, other: &Ipv4Addr) -> bool {
+        self.netmask == Self::LENGTH && self.network_address == *other
+    }
+}
+
+impl PartialEq<Ipv4Network> for Ipv4Addr {
+    #[inline]
+    fn eq(&self, other: &Ipv4Network) -> bool {
+        other == self
+    }
+}
+
+impl BitAnd<Ipv4Addr> for &Ipv4Network {
+    type Output = Ipv4Addr;
+
+    /// Returns given IP address with host bits cleared, same as [`Ipv4Network::mask`].
+    ///
+    /// [`Ipv4Network::mask`]: struct.Ipv4Network.html#method.mask
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24)?;
+    /// assert_eq!(&ip_network & Ipv4Addr::new(10, 0, 0, 200), Ipv4Addr::new(10, 0, 0, 0));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    fn bitand(self, ip: Ipv4Addr) -> Ipv4Addr {
+        self.mask(ip)
+    }
+}
+
+/// Feeds network address and then netmask to given `Hasher`. Resulting value depends on `Hasher`
+/// and on how `std` hashes `Ipv4Addr`, so it can differ between program runs, platforms and Rust
+/// versions. For hash value that is guaranteed to not change, use [`Ipv4Network::stable_hash`].
+///
+/// [`Ipv4Network::stable_hash`]: struct.Ipv4Network.html#method.stable_hash
+impl Hash for Ipv4Network {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.network_address.hash(state);
+        self.netmask.hash(state);
+    }
+}
+
+impl IntoIterator for Ipv4Network {
+    type Item = Ipv4Addr;
+    type IntoIter = iterator::Ipv4RangeIterator;
+
+    /// Returns iterator over all IP addresses in range including network and broadcast addresses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip = Ipv4Addr::new(192, 168, 1, 0);
+    /// let mut iter = Ipv4Network::new(ip, 24)?.into_iter();
+    /// assert_eq!(iter.next().unwrap(), Ipv4Addr::new(192, 168, 1, 0));
+    /// assert_eq!(iter.next().unwrap(), Ipv4Addr::new(192, 168, 1, 1));
+    /// assert_eq!(iter.last().unwrap(), Ipv4Addr::new(192, 168, 1, 255));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
         Self::IntoIter::new(self.network_address, self.broadcast_address())
     }
 }
@@ -831,95 +2206,508 @@ impl IntoIterator for Ipv4Network {
 #[cfg(test)]
 mod tests {
     use std::net::Ipv4Addr;
-    use crate::{IpNetworkError, Ipv4Network};
+    use crate::{IpNetworkError, IpNetworkParseError, Ipv4Network, Ipv6Network};
+    use crate::helpers::FixedBuffer;
     use std::str::FromStr;
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
 
-    fn return_test_ipv4_network() -> Ipv4Network {
-        Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap()
+    fn return_test_ipv4_network() -> Ipv4Network {
+        Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap()
+    }
+
+    #[test]
+    fn default_route() {
+        let network = Ipv4Network::DEFAULT_ROUTE;
+        assert!(network.is_default_route());
+    }
+
+    #[test]
+    fn new_host_bits_set() {
+        let ip = Ipv4Addr::new(127, 0, 0, 1);
+        let ip_network = Ipv4Network::new(ip, 8);
+        assert!(ip_network.is_err());
+        assert_eq!(IpNetworkError::HostBitsSet, ip_network.unwrap_err());
+    }
+
+    #[test]
+    fn new_host_bits_set_no_31() {
+        let ip = Ipv4Addr::new(127, 0, 0, 2);
+        let ip_network = Ipv4Network::new(ip, 31);
+        assert!(ip_network.is_ok());
+    }
+
+    #[test]
+    fn new_host_bits_set_no_32() {
+        let ip = Ipv4Addr::new(127, 0, 0, 1);
+        let ip_network = Ipv4Network::new(ip, 32);
+        assert!(ip_network.is_ok());
+    }
+
+    #[test]
+    fn new_host_bits_set_no_zero() {
+        let ip = Ipv4Addr::new(0, 0, 0, 0);
+        let ip_network = Ipv4Network::new(ip, 0);
+        assert!(ip_network.is_ok());
+    }
+
+    #[test]
+    fn new_big_invalid_netmask() {
+        let ip = Ipv4Addr::new(127, 0, 0, 1);
+        let ip_network = Ipv4Network::new(ip, 33);
+        assert!(ip_network.is_err());
+        assert_eq!(IpNetworkError::NetmaskError(33), ip_network.unwrap_err());
+    }
+
+    #[test]
+    fn new_truncate_host_bits_set() {
+        let ip = Ipv4Addr::new(127, 0, 0, 1);
+        let ip_network = Ipv4Network::new_truncate(ip, 8).unwrap();
+        assert_eq!(ip_network.network_address(), Ipv4Addr::new(127, 0, 0, 0));
+    }
+
+    #[test]
+    fn with_netmask() {
+        let ip = Ipv4Addr::new(192, 168, 0, 0);
+        assert_eq!(
+            Ipv4Network::new(ip, 16).unwrap(),
+            Ipv4Network::with_netmask(ip, Ipv4Addr::new(255, 255, 0, 0)).unwrap()
+        );
+        assert_eq!(
+            Ipv4Network::DEFAULT_ROUTE,
+            Ipv4Network::with_netmask(Ipv4Addr::UNSPECIFIED, Ipv4Addr::UNSPECIFIED).unwrap()
+        );
+    }
+
+    #[test]
+    fn with_netmask_not_contiguous() {
+        let ip = Ipv4Addr::new(192, 168, 0, 0);
+        assert_eq!(
+            IpNetworkError::NonContiguousNetmask(Ipv4Addr::new(255, 255, 0, 255)),
+            Ipv4Network::with_netmask(ip, Ipv4Addr::new(255, 255, 0, 255)).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn with_netmask_host_bits_set() {
+        let ip = Ipv4Addr::new(192, 168, 1, 0);
+        assert_eq!(
+            IpNetworkError::HostBitsSet,
+            Ipv4Network::with_netmask(ip, Ipv4Addr::new(255, 255, 0, 0)).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn new_truncate_big_invalid_netmask() {
+        let ip = Ipv4Addr::new(127, 0, 0, 1);
+        let ip_network = Ipv4Network::new_truncate(ip, 33);
+        assert!(ip_network.is_err());
+        assert_eq!(IpNetworkError::NetmaskError(33), ip_network.unwrap_err());
+    }
+
+    #[test]
+    fn new_accepts_only_masked_addresses() {
+        crate::helpers::test_new_accepts_only_masked_addresses!(Ipv4Network, Ipv4Addr, u32);
+
+        // Boundary prefixes
+        assert!(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 0).is_ok());
+        assert!(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 1), 0).is_err());
+        assert!(Ipv4Network::new(Ipv4Addr::new(128, 0, 0, 0), 0).is_err());
+        assert!(Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 255), 32).is_ok());
+        assert!(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 1), 32).is_ok());
+    }
+
+    #[test]
+    fn to_ipv6_mapped() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        let ipv6_network = ip_network.to_ipv6_mapped();
+        assert_eq!(
+            "::ffff:192.168.1.0/120".parse::<Ipv6Network>().unwrap(),
+            ipv6_network
+        );
+        assert_eq!(Some(ip_network), ipv6_network.to_ipv4());
+    }
+
+    #[test]
+    fn to_ipv6_compatible() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        let ipv6_network = ip_network.to_ipv6_compatible();
+        assert_eq!(
+            "::192.168.1.0/120".parse::<Ipv6Network>().unwrap(),
+            ipv6_network
+        );
+        assert_eq!(Some(ip_network), ipv6_network.to_ipv4());
+
+        let ipv6_network = Ipv4Network::DEFAULT_ROUTE.to_ipv6_compatible();
+        assert_eq!("::/96".parse::<Ipv6Network>().unwrap(), ipv6_network);
+        assert_eq!(Some(Ipv4Network::DEFAULT_ROUTE), ipv6_network.to_ipv4());
+    }
+
+    #[test]
+    fn to_ipv6() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+
+        let mapped = ip_network.to_ipv6(true);
+        assert_eq!(
+            "::ffff:192.168.1.0/120".parse::<Ipv6Network>().unwrap(),
+            mapped
+        );
+        assert!("::ffff:0:0/96"
+            .parse::<Ipv6Network>()
+            .unwrap()
+            .contains(mapped));
+
+        let compatible = ip_network.to_ipv6(false);
+        assert_eq!(
+            "::192.168.1.0/120".parse::<Ipv6Network>().unwrap(),
+            compatible
+        );
+        assert!("::/96".parse::<Ipv6Network>().unwrap().contains(compatible));
+    }
+
+    #[test]
+    fn packed_round_trip() {
+        let networks = [
+            Ipv4Network::DEFAULT_ROUTE,
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 255), 32).unwrap(),
+        ];
+        for ip_network in &networks {
+            assert_eq!(
+                *ip_network,
+                Ipv4Network::from_packed(ip_network.to_packed()).unwrap()
+            );
+        }
+        assert!(networks[0].to_packed() < networks[1].to_packed());
+    }
+
+    #[test]
+    fn from_packed_invalid_netmask() {
+        assert_eq!(
+            IpNetworkError::NetmaskError(33),
+            Ipv4Network::from_packed(0xC0A8_0100_0000_0021).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_packed_host_bits_set() {
+        assert_eq!(
+            IpNetworkError::HostBitsSet,
+            Ipv4Network::from_packed(0xC0A8_0101_0000_0018).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn from_packed_padding_set() {
+        assert!(Ipv4Network::from_packed(0xC0A8_0100_0000_0118).is_err());
+    }
+
+    #[test]
+    fn bits_round_trip() {
+        let networks = [
+            Ipv4Network::DEFAULT_ROUTE,
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 255), 32).unwrap(),
+        ];
+        for ip_network in &networks {
+            let (bits, netmask) = ip_network.to_bits();
+            assert_eq!(*ip_network, Ipv4Network::from_bits(bits, netmask).unwrap());
+        }
+    }
+
+    #[test]
+    fn from_bits_host_bits_set() {
+        assert_eq!(
+            IpNetworkError::HostBitsSet,
+            Ipv4Network::from_bits(0xC0A8_0101, 24).unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkError::NetmaskError(33),
+            Ipv4Network::from_bits(0xC0A8_0101, 33).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn eq_address() {
+        let ip = Ipv4Addr::new(192, 168, 1, 1);
+        let ip_network = Ipv4Network::from(ip);
+        assert!(ip_network == ip);
+        assert!(ip == ip_network);
+        assert!(ip_network != Ipv4Addr::new(192, 168, 1, 0));
+    }
+
+    #[test]
+    fn eq_address_not_host_network() {
+        let ip = Ipv4Addr::new(192, 168, 1, 0);
+        let ip_network = Ipv4Network::new(ip, 24).unwrap();
+        assert!(ip_network != ip);
+        assert!(ip != ip_network);
+    }
+
+    #[test]
+    fn octets() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!([192, 168, 1, 0], ip_network.octets());
+    }
+
+    #[test]
+    fn network_and_host_bits() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!((24, 8), (ip_network.network_bits(), ip_network.host_bits()));
+
+        assert_eq!(
+            0,
+            Ipv4Network::from(Ipv4Addr::new(192, 168, 1, 0)).host_bits()
+        );
+    }
+
+    #[test]
+    fn new_const() {
+        const NETWORK: Ipv4Network = Ipv4Network::new_const(Ipv4Addr::new(192, 168, 0, 0), 16);
+        assert_eq!(
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap(),
+            NETWORK
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))] // currently, miri doesnt support should_panic tests
+    fn new_const_host_bits_set() {
+        Ipv4Network::new_const(Ipv4Addr::new(192, 168, 0, 1), 16);
+    }
+
+    #[test]
+    fn const_context() {
+        const NETWORK: Ipv4Network = match Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16) {
+            Ok(network) => network,
+            Err(_) => panic!("invalid network"),
+        };
+        const TRUNCATED: Ipv4Network =
+            match Ipv4Network::new_truncate(Ipv4Addr::new(192, 168, 1, 1), 24) {
+                Ok(network) => network,
+                Err(_) => panic!("invalid network"),
+            };
+        // `contains` is generic over `Contained` and can't be `const fn`,
+        // `contains_address` is its const counterpart.
+        const CONTAINS: bool = NETWORK.contains_address(Ipv4Addr::new(192, 168, 1, 1));
+        const CONTAINS_NETWORK: bool = NETWORK.contains_network(&TRUNCATED);
+        const NOT_CONTAINS: bool = NETWORK.contains_address(Ipv4Addr::new(10, 0, 0, 1));
+
+        const _: () = assert!(CONTAINS);
+        const _: () = assert!(CONTAINS_NETWORK);
+        const _: () = assert!(!NOT_CONTAINS);
+        assert_eq!(Ipv4Addr::new(192, 168, 1, 0), TRUNCATED.network_address());
+        assert_eq!(24, TRUNCATED.netmask());
+    }
+
+    #[test]
+    fn basic_getters() {
+        let ip_network = return_test_ipv4_network();
+        assert_eq!(ip_network.network_address(), Ipv4Addr::new(192, 168, 0, 0));
+        assert_eq!(ip_network.netmask(), 16);
+        assert_eq!(
+            ip_network.broadcast_address(),
+            Ipv4Addr::new(192, 168, 255, 255)
+        );
+        assert_eq!(ip_network.full_netmask(), Ipv4Addr::new(255, 255, 0, 0));
+        assert_eq!(
+            ip_network.supernet(),
+            Some(Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 15).unwrap())
+        );
+        assert_eq!(ip_network.hosts().len(), 256 * 256 - 2);
+    }
+
+    #[test]
+    fn host_network_hosts() {
+        let ip = Ipv4Addr::new(127, 0, 0, 1);
+        let ip_network = Ipv4Network::new(ip, 32).unwrap();
+        assert_eq!(1, ip_network.hosts().len());
+    }
+
+    #[test]
+    fn hosts_len_30() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 30).unwrap();
+        let mut hosts = ip_network.hosts();
+        assert_eq!(2, hosts.len());
+        assert_eq!(Some(Ipv4Addr::new(192, 168, 1, 1)), hosts.next());
+        assert_eq!(1, hosts.len());
+        assert_eq!(Some(Ipv4Addr::new(192, 168, 1, 2)), hosts.next());
+        assert_eq!(0, hosts.len());
+        assert_eq!(None, hosts.next());
+    }
+
+    #[test]
+    fn hosts_len_31() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 31).unwrap();
+        let mut hosts = ip_network.hosts();
+        assert_eq!(2, hosts.len());
+        assert_eq!(Some(Ipv4Addr::new(192, 168, 1, 0)), hosts.next());
+        assert_eq!(Some(Ipv4Addr::new(192, 168, 1, 1)), hosts.next());
+        assert_eq!(0, hosts.len());
+        assert_eq!(None, hosts.next());
     }
 
     #[test]
-    fn default_route() {
-        let network = Ipv4Network::DEFAULT_ROUTE;
-        assert!(network.is_default_route());
+    fn hosts_len_32() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32).unwrap();
+        let mut hosts = ip_network.hosts();
+        assert_eq!(1, hosts.len());
+        assert_eq!(Some(Ipv4Addr::new(192, 168, 1, 1)), hosts.next());
+        assert_eq!(0, hosts.len());
+        assert_eq!(None, hosts.next());
     }
 
     #[test]
-    fn new_host_bits_set() {
-        let ip = Ipv4Addr::new(127, 0, 0, 1);
-        let ip_network = Ipv4Network::new(ip, 8);
-        assert!(ip_network.is_err());
-        assert_eq!(IpNetworkError::HostBitsSet, ip_network.unwrap_err());
+    fn hosts_chunked() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 28).unwrap();
+        let chunks = ip_network.hosts_chunked(4).collect::<Vec<_>>();
+        assert_eq!(4, chunks.len());
+        assert_eq!(
+            vec![4, 4, 4, 2],
+            chunks.iter().map(Vec::len).collect::<Vec<_>>()
+        );
+        assert_eq!(ip_network.hosts().collect::<Vec<_>>(), chunks.concat());
     }
 
     #[test]
-    fn new_host_bits_set_no_31() {
-        let ip = Ipv4Addr::new(127, 0, 0, 2);
-        let ip_network = Ipv4Network::new(ip, 31);
-        assert!(ip_network.is_ok());
+    fn hosts_chunked_host_network() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 1), 32).unwrap();
+        assert_eq!(
+            vec![vec![Ipv4Addr::new(127, 0, 0, 1)]],
+            ip_network.hosts_chunked(4).collect::<Vec<_>>()
+        );
     }
 
     #[test]
-    fn new_host_bits_set_no_32() {
-        let ip = Ipv4Addr::new(127, 0, 0, 1);
-        let ip_network = Ipv4Network::new(ip, 32);
-        assert!(ip_network.is_ok());
+    fn addresses_stride() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+        assert_eq!(
+            vec![
+                Ipv4Addr::new(10, 0, 0, 0),
+                Ipv4Addr::new(10, 0, 0, 64),
+                Ipv4Addr::new(10, 0, 0, 128),
+                Ipv4Addr::new(10, 0, 0, 192),
+            ],
+            ip_network.addresses_stride(64).collect::<Vec<_>>()
+        );
+        assert_eq!(256, ip_network.addresses_stride(1).count());
+        assert_eq!(1, ip_network.addresses_stride(256).count());
+        assert_eq!(
+            vec![Ipv4Addr::new(10, 0, 0, 0)],
+            ip_network.addresses_stride(0).collect::<Vec<_>>()
+        );
     }
 
     #[test]
-    fn new_host_bits_set_no_zero() {
-        let ip = Ipv4Addr::new(0, 0, 0, 0);
-        let ip_network = Ipv4Network::new(ip, 0);
-        assert!(ip_network.is_ok());
+    fn intersection_addresses_nested() {
+        let parent = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+        let child = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 64), 29).unwrap();
+        let expected = child.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(8, expected.len());
+        assert_eq!(
+            expected,
+            parent.intersection_addresses(&child).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            expected,
+            child.intersection_addresses(&parent).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            expected,
+            child.intersection_addresses(&child).collect::<Vec<_>>()
+        );
     }
 
     #[test]
-    fn new_big_invalid_netmask() {
-        let ip = Ipv4Addr::new(127, 0, 0, 1);
-        let ip_network = Ipv4Network::new(ip, 33);
-        assert!(ip_network.is_err());
-        assert_eq!(IpNetworkError::NetmaskError(33), ip_network.unwrap_err());
+    fn intersection_addresses_disjoint() {
+        let a = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 25).unwrap();
+        let b = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 128), 25).unwrap();
+        assert_eq!(0, a.intersection_addresses(&b).count());
+        assert_eq!(0, b.intersection_addresses(&a).count());
     }
 
     #[test]
-    fn new_truncate_host_bits_set() {
-        let ip = Ipv4Addr::new(127, 0, 0, 1);
-        let ip_network = Ipv4Network::new_truncate(ip, 8).unwrap();
-        assert_eq!(ip_network.network_address(), Ipv4Addr::new(127, 0, 0, 0));
+    fn addresses_stride_whole_range() {
+        let mut addresses = Ipv4Network::DEFAULT_ROUTE.addresses_stride(u32::MAX);
+        assert_eq!(Some(Ipv4Addr::new(0, 0, 0, 0)), addresses.next());
+        assert_eq!(Some(Ipv4Addr::new(255, 255, 255, 255)), addresses.next());
+        assert_eq!(None, addresses.next());
     }
 
     #[test]
-    fn new_truncate_big_invalid_netmask() {
-        let ip = Ipv4Addr::new(127, 0, 0, 1);
-        let ip_network = Ipv4Network::new_truncate(ip, 33);
-        assert!(ip_network.is_err());
-        assert_eq!(IpNetworkError::NetmaskError(33), ip_network.unwrap_err());
+    fn adjust_prefix() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!(
+            Some(Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 22).unwrap()),
+            ip_network.adjust_prefix(2)
+        );
+        assert_eq!(
+            Some(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 26).unwrap()),
+            ip_network.adjust_prefix(-2)
+        );
+        assert_eq!(Some(ip_network), ip_network.adjust_prefix(0));
     }
 
     #[test]
-    fn basic_getters() {
-        let ip_network = return_test_ipv4_network();
-        assert_eq!(ip_network.network_address(), Ipv4Addr::new(192, 168, 0, 0));
-        assert_eq!(ip_network.netmask(), 16);
+    fn adjust_prefix_out_of_range() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!(None, ip_network.adjust_prefix(25));
+        assert_eq!(None, ip_network.adjust_prefix(-9));
+        assert_eq!(None, ip_network.adjust_prefix(i8::MIN));
         assert_eq!(
-            ip_network.broadcast_address(),
-            Ipv4Addr::new(192, 168, 255, 255)
+            Some(Ipv4Network::DEFAULT_ROUTE),
+            ip_network.adjust_prefix(24)
         );
-        assert_eq!(ip_network.full_netmask(), Ipv4Addr::new(255, 255, 0, 0));
+    }
+
+    #[test]
+    fn next_wrapping() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 255, 0), 24).unwrap();
         assert_eq!(
-            ip_network.supernet(),
-            Some(Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 15).unwrap())
+            Ipv4Network::new(Ipv4Addr::new(10, 1, 0, 0), 24).unwrap(),
+            ip_network.next_wrapping()
         );
-        assert_eq!(ip_network.hosts().len(), 256 * 256 - 2);
     }
 
     #[test]
-    fn host_network_without_hosts() {
-        let ip = Ipv4Addr::new(127, 0, 0, 1);
-        let ip_network = Ipv4Network::new(ip, 32).unwrap();
-        assert_eq!(0, ip_network.hosts().len());
+    fn next_wrapping_top_boundary() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 0), 24).unwrap();
+        assert_eq!(
+            Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 24).unwrap(),
+            ip_network.next_wrapping()
+        );
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 255), 32).unwrap();
+        assert_eq!(
+            Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 32).unwrap(),
+            ip_network.next_wrapping()
+        );
+
+        assert_eq!(
+            Ipv4Network::DEFAULT_ROUTE,
+            Ipv4Network::DEFAULT_ROUTE.next_wrapping()
+        );
+    }
+
+    #[test]
+    fn supernets() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(240, 0, 0, 0), 4).unwrap();
+        assert_eq!(
+            vec![
+                Ipv4Network::new(Ipv4Addr::new(224, 0, 0, 0), 3).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 0, 0, 0), 2).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(128, 0, 0, 0), 1).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 0).unwrap(),
+            ],
+            ip_network.supernets().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn supernets_default_route() {
+        assert_eq!(0, Ipv4Network::DEFAULT_ROUTE.supernets().len());
     }
 
     #[test]
@@ -929,85 +2717,433 @@ mod tests {
     }
 
     #[test]
-    fn iterator() {
-        let ip_network = return_test_ipv4_network();
-        assert_eq!(ip_network.into_iter().len(), 256 * 256);
+    fn enclosing_in_longest_match() {
+        let candidates = [
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap(),
+            Ipv4Network::DEFAULT_ROUTE,
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 23).unwrap(),
+        ];
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 26).unwrap();
+        assert_eq!(Some(candidates[2]), ip_network.enclosing_in(&candidates));
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!(Some(candidates[2]), ip_network.enclosing_in(&candidates));
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 2, 0), 24).unwrap();
+        assert_eq!(Some(candidates[0]), ip_network.enclosing_in(&candidates));
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        assert_eq!(
+            Some(Ipv4Network::DEFAULT_ROUTE),
+            ip_network.enclosing_in(&candidates)
+        );
+    }
+
+    #[test]
+    fn enclosing_in_no_match() {
+        let candidates = [Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap()];
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+        assert_eq!(None, ip_network.enclosing_in(&candidates));
+        assert_eq!(None, ip_network.enclosing_in(&[]));
+    }
+
+    #[test]
+    fn enclosing_prefixes() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(224, 0, 0, 0), 3).unwrap();
+        let enclosing = ip_network.enclosing_prefixes().collect::<Vec<_>>();
+        assert_eq!(
+            enclosing,
+            vec![
+                Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 0).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(128, 0, 0, 0), 1).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 0, 0, 0), 2).unwrap(),
+                ip_network,
+            ]
+        );
+    }
+
+    #[test]
+    fn iterator() {
+        let ip_network = return_test_ipv4_network();
+        assert_eq!(ip_network.into_iter().len(), 256 * 256);
+    }
+
+    #[test]
+    fn iterator_for() {
+        let mut i = 0;
+        for _ in return_test_ipv4_network() {
+            i += 1;
+        }
+        assert_eq!(i, 256 * 256);
+    }
+
+    #[test]
+    fn contains() {
+        let ip_network = return_test_ipv4_network();
+        assert!(!ip_network.contains(Ipv4Addr::new(192, 167, 255, 255)));
+        assert!(ip_network.contains(Ipv4Addr::new(192, 168, 0, 0)));
+        assert!(ip_network.contains(Ipv4Addr::new(192, 168, 255, 255)));
+        assert!(!ip_network.contains(Ipv4Addr::new(192, 169, 0, 0)));
+    }
+
+    #[test]
+    fn contains_default_route() {
+        assert!(Ipv4Network::DEFAULT_ROUTE.contains(Ipv4Addr::new(0, 0, 0, 0)));
+        assert!(Ipv4Network::DEFAULT_ROUTE.contains(Ipv4Addr::new(203, 0, 113, 7)));
+        assert!(Ipv4Network::DEFAULT_ROUTE.contains(Ipv4Addr::new(255, 255, 255, 255)));
+    }
+
+    #[test]
+    fn contains_network() {
+        let ip_network = return_test_ipv4_network();
+        assert!(ip_network.contains_network(&ip_network));
+        assert!(ip_network.contains_network(&Ipv4Network::from_str("192.168.255.0/24").unwrap()));
+        assert!(!ip_network.contains_network(&Ipv4Network::from_str("192.168.0.0/15").unwrap()));
+        assert!(!ip_network.contains_network(&Ipv4Network::from_str("192.169.0.0/24").unwrap()));
+        assert!(Ipv4Network::DEFAULT_ROUTE.contains_network(&ip_network));
+    }
+
+    #[test]
+    fn subnets() {
+        let ip_network = return_test_ipv4_network();
+        let mut subnets = ip_network.subnets();
+        assert_eq!(subnets.len(), 2);
+        assert_eq!(
+            subnets.next().unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 17).unwrap()
+        );
+        assert_eq!(
+            subnets.next().unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 128, 0), 17).unwrap()
+        );
+        assert!(subnets.next().is_none());
+    }
+
+    #[test]
+    fn subnets_none() {
+        let ipv4_network = Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 32).unwrap();
+        assert_eq!(0, ipv4_network.subnets().len());
+    }
+
+    #[test]
+    fn for_each_subnet_down_to() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 22).unwrap();
+        let mut visited = vec![];
+        ip_network.for_each_subnet_down_to(24, |network| visited.push(network));
+        assert_eq!(7, visited.len());
+        assert_eq!(
+            vec![
+                ip_network,
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 23).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 24).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 2, 0), 23).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 2, 0), 24).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 3, 0), 24).unwrap(),
+            ],
+            visited
+        );
+    }
+
+    #[test]
+    fn for_each_subnet_down_to_same_prefix() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 32).unwrap();
+        let mut count = 0;
+        ip_network.for_each_subnet_down_to(32, |_| count += 1);
+        assert_eq!(1, count);
+    }
+
+    #[test]
+    fn partition() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        let subnets = ip_network.partition(26).unwrap();
+        assert_eq!(
+            vec![
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 26).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 64), 26).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 26).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 192), 26).unwrap(),
+            ],
+            subnets
+        );
+        assert_eq!(4, subnets.capacity());
+    }
+
+    #[test]
+    fn vlsm() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 22).unwrap();
+        let subnets = ip_network.vlsm(&[50, 500, 100]).unwrap();
+        assert_eq!(
+            vec![
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 2, 128), 26).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 23).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 2, 0), 25).unwrap(),
+            ],
+            subnets
+        );
+
+        // Host count is the same as length of `hosts` of the smallest network that fits
+        for count in 0..300 {
+            let subnet = ip_network.vlsm(&[count]).unwrap()[0];
+            assert!(subnet.hosts().len() >= count as usize);
+            assert!(subnet
+                .subnets()
+                .all(|half| half.hosts().len() < count as usize));
+        }
+
+        let subnets = ip_network.vlsm(&[500, 100, 50]).unwrap();
+        assert_eq!(
+            vec![
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 23).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 2, 0), 25).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 2, 128), 26).unwrap(),
+            ],
+            subnets
+        );
+    }
+
+    #[test]
+    fn vlsm_edge_cases() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 30).unwrap();
+        assert_eq!(
+            vec![
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 2), 32).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 31).unwrap(),
+            ],
+            ip_network.vlsm(&[1, 2]).unwrap()
+        );
+        assert_eq!(Vec::<Ipv4Network>::new(), ip_network.vlsm(&[]).unwrap());
+        assert_eq!(
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 32).unwrap(),
+            ip_network.vlsm(&[0]).unwrap()[0]
+        );
+        assert_eq!(
+            Err(IpNetworkError::NotEnoughAddresses(3)),
+            ip_network.vlsm(&[3])
+        );
+        assert_eq!(
+            Err(IpNetworkError::NotEnoughAddresses(1)),
+            ip_network.vlsm(&[2, 2, 1])
+        );
+        assert_eq!(
+            Err(IpNetworkError::NotEnoughAddresses(u32::MAX)),
+            Ipv4Network::DEFAULT_ROUTE.vlsm(&[u32::MAX])
+        );
+    }
+
+    #[test]
+    fn partition_invalid_prefix() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!(
+            IpNetworkError::NetmaskError(24),
+            ip_network.partition(24).unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkError::NetmaskError(16),
+            ip_network.partition(16).unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkError::NetmaskError(33),
+            ip_network.partition(33).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn subnets_with_prefix() {
+        let ip_network = return_test_ipv4_network();
+        let mut subnets = ip_network.subnets_with_prefix(18);
+        assert_eq!(subnets.len(), 4);
+        assert_eq!(
+            subnets.next().unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 18).unwrap()
+        );
+        assert_eq!(
+            subnets.next().unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 64, 0), 18).unwrap()
+        );
+        assert_eq!(
+            subnets.next().unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 128, 0), 18).unwrap()
+        );
+        assert_eq!(
+            subnets.next().unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 192, 0), 18).unwrap()
+        );
+        assert!(subnets.next().is_none());
+    }
+
+    #[test]
+    fn parse() {
+        let ip_network: Ipv4Network = "192.168.0.0/16".parse().unwrap();
+        assert_eq!(ip_network, return_test_ipv4_network());
+    }
+
+    #[test]
+    fn from_wildcard() {
+        let ip_network = Ipv4Network::from_wildcard("10.*.*.*").unwrap();
+        assert_eq!(
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap(),
+            ip_network
+        );
+
+        let ip_network = Ipv4Network::from_wildcard("*.*.*.*").unwrap();
+        assert_eq!(Ipv4Network::DEFAULT_ROUTE, ip_network);
+
+        let ip_network = Ipv4Network::from_wildcard("192.168.1.1").unwrap();
+        assert_eq!(
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32).unwrap(),
+            ip_network
+        );
+    }
+
+    #[test]
+    fn from_wildcard_invalid() {
+        assert_eq!(
+            IpNetworkParseError::InvalidFormatError,
+            Ipv4Network::from_wildcard("192.*.1.*").unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::InvalidFormatError,
+            Ipv4Network::from_wildcard("192.168.1*.*").unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::AddrParseError,
+            Ipv4Network::from_wildcard("192.168.*").unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::AddrParseError,
+            Ipv4Network::from_wildcard("192.300.*.*").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_invalid_netmask_span() {
+        let ip_network = "192.168.0.0/2x".parse::<Ipv4Network>();
+        assert_eq!(
+            IpNetworkParseError::InvalidNetmaskFormat { span: 12..14 },
+            ip_network.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_without_netmask() {
+        assert_eq!(
+            Ipv4Network::from(Ipv4Addr::new(10, 0, 0, 5)),
+            "10.0.0.5".parse::<Ipv4Network>().unwrap()
+        );
+        assert_eq!(
+            IpNetworkParseError::InvalidFormatError,
+            "1.2.3.4/".parse::<Ipv4Network>().unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkParseError::InvalidFormatError,
+            "".parse::<Ipv4Network>().unwrap_err()
+        );
     }
 
     #[test]
-    fn iterator_for() {
-        let mut i = 0;
-        for _ in return_test_ipv4_network() {
-            i += 1;
-        }
-        assert_eq!(i, 256 * 256);
+    fn from_str_truncate_without_netmask() {
+        assert_eq!(
+            Ipv4Network::from(Ipv4Addr::new(10, 0, 0, 5)),
+            Ipv4Network::from_str_truncate("10.0.0.5").unwrap()
+        );
+        assert_eq!(
+            IpNetworkParseError::InvalidFormatError,
+            Ipv4Network::from_str_truncate("10.0.0.5/").unwrap_err()
+        );
     }
 
     #[test]
-    fn contains() {
-        let ip_network = return_test_ipv4_network();
-        assert!(!ip_network.contains(Ipv4Addr::new(192, 167, 255, 255)));
-        assert!(ip_network.contains(Ipv4Addr::new(192, 168, 0, 0)));
-        assert!(ip_network.contains(Ipv4Addr::new(192, 168, 255, 255)));
-        assert!(!ip_network.contains(Ipv4Addr::new(192, 169, 0, 0)));
+    fn parse_dotted_netmask() {
+        assert_eq!(
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 24).unwrap(),
+            "192.168.0.0/255.255.255.0".parse::<Ipv4Network>().unwrap()
+        );
+        assert_eq!(
+            Ipv4Network::DEFAULT_ROUTE,
+            "0.0.0.0/0.0.0.0".parse::<Ipv4Network>().unwrap()
+        );
+        assert_eq!(
+            IpNetworkParseError::IpNetworkError(IpNetworkError::HostBitsSet),
+            "192.168.1.0/255.255.0.0"
+                .parse::<Ipv4Network>()
+                .unwrap_err()
+        );
     }
 
     #[test]
-    fn subnets() {
-        let ip_network = return_test_ipv4_network();
-        let mut subnets = ip_network.subnets();
-        assert_eq!(subnets.len(), 2);
+    fn from_str_truncate_dotted_netmask() {
         assert_eq!(
-            subnets.next().unwrap(),
-            Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 17).unwrap()
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap(),
+            Ipv4Network::from_str_truncate("10.0.0.1/255.0.0.0").unwrap()
         );
         assert_eq!(
-            subnets.next().unwrap(),
-            Ipv4Network::new(Ipv4Addr::new(192, 168, 128, 0), 17).unwrap()
+            IpNetworkParseError::InvalidNetmaskFormat { span: 9..20 },
+            Ipv4Network::from_str_truncate("10.0.0.1/255.0.255.0").unwrap_err()
         );
-        assert!(subnets.next().is_none());
     }
 
     #[test]
-    fn subnets_none() {
-        let ipv4_network = Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 32).unwrap();
-        assert_eq!(0, ipv4_network.subnets().len());
+    fn parse_dotted_netmask_not_contiguous() {
+        assert_eq!(
+            IpNetworkParseError::InvalidNetmaskFormat { span: 12..23 },
+            "192.168.0.0/255.0.255.0"
+                .parse::<Ipv4Network>()
+                .unwrap_err()
+        );
     }
 
     #[test]
-    fn subnets_with_prefix() {
-        let ip_network = return_test_ipv4_network();
-        let mut subnets = ip_network.subnets_with_prefix(18);
-        assert_eq!(subnets.len(), 4);
+    fn parse_octet_out_of_range() {
         assert_eq!(
-            subnets.next().unwrap(),
-            Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 18).unwrap()
+            IpNetworkParseError::AddrOctetOutOfRange { span: 0..3 },
+            "300.0.0.0/8".parse::<Ipv4Network>().unwrap_err()
         );
         assert_eq!(
-            subnets.next().unwrap(),
-            Ipv4Network::new(Ipv4Addr::new(192, 168, 64, 0), 18).unwrap()
+            IpNetworkParseError::AddrOctetOutOfRange { span: 8..11 },
+            Ipv4Network::from_str_truncate("192.168.256.1/24").unwrap_err()
         );
+    }
+
+    #[test]
+    fn parse_bad_address_format() {
         assert_eq!(
-            subnets.next().unwrap(),
-            Ipv4Network::new(Ipv4Addr::new(192, 168, 128, 0), 18).unwrap()
+            IpNetworkParseError::AddrParseError,
+            "1.2.3/8".parse::<Ipv4Network>().unwrap_err()
         );
         assert_eq!(
-            subnets.next().unwrap(),
-            Ipv4Network::new(Ipv4Addr::new(192, 168, 192, 0), 18).unwrap()
+            IpNetworkParseError::AddrParseError,
+            "1.2.x.0/8".parse::<Ipv4Network>().unwrap_err()
         );
-        assert!(subnets.next().is_none());
     }
 
     #[test]
-    fn parse() {
-        let ip_network: Ipv4Network = "192.168.0.0/16".parse().unwrap();
-        assert_eq!(ip_network, return_test_ipv4_network());
+    fn format() {
+        let ip_network = return_test_ipv4_network();
+        assert_eq!(ip_network.to_string(), "192.168.0.0/16");
     }
 
     #[test]
-    fn format() {
+    fn write_cidr_fixed_buffer() {
+        let mut buffer = FixedBuffer::<18>::new();
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 0), 24).unwrap();
+        ip_network.write_cidr(&mut buffer).unwrap();
+        assert_eq!(buffer.as_str(), "255.255.255.0/24");
+
+        // Buffer doesn't have enough capacity for second network
+        assert!(ip_network.write_cidr(&mut buffer).is_err());
+    }
+
+    #[test]
+    fn format_padding() {
         let ip_network = return_test_ipv4_network();
-        assert_eq!(ip_network.to_string(), "192.168.0.0/16");
+        assert_eq!(format!("{:>18}", ip_network), "    192.168.0.0/16");
+        assert_eq!(format!("{:<18}|", ip_network), "192.168.0.0/16    |");
+        assert_eq!(format!("{:*^18}", ip_network), "**192.168.0.0/16**");
+        assert_eq!(format!("{:>4}", ip_network), "192.168.0.0/16");
     }
 
     #[test]
@@ -1043,6 +3179,263 @@ mod tests {
         assert!(!is_private(Ipv4Addr::new(192, 168, 0, 0), 15));
     }
 
+    #[test]
+    fn netmask_info() {
+        let netmask = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 64), 26)
+            .unwrap()
+            .netmask_info();
+        assert_eq!(26, netmask.prefix_len());
+        assert_eq!(Ipv4Addr::new(255, 255, 255, 192), netmask.to_ipv4());
+        assert_eq!(0xffff_ffc0, netmask.to_u32());
+
+        let netmask = Ipv4Network::DEFAULT_ROUTE.netmask_info();
+        assert_eq!(0, netmask.prefix_len());
+        assert_eq!(Ipv4Addr::new(0, 0, 0, 0), netmask.to_ipv4());
+        assert_eq!(0, netmask.to_u32());
+    }
+
+    #[test]
+    fn halves() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!(
+            Some((
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 25).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 25).unwrap(),
+            )),
+            ip_network.halves()
+        );
+        assert_eq!(
+            Some((
+                Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 1).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(128, 0, 0, 0), 1).unwrap(),
+            )),
+            Ipv4Network::DEFAULT_ROUTE.halves()
+        );
+        assert_eq!(
+            None,
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32)
+                .unwrap()
+                .halves()
+        );
+    }
+
+    #[test]
+    fn is_default() {
+        assert!(Ipv4Network::DEFAULT_ROUTE.is_default());
+        assert!(!Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 1)
+            .unwrap()
+            .is_default());
+        assert!(!Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 32)
+            .unwrap()
+            .is_default());
+    }
+
+    #[test]
+    fn sibling() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 25).unwrap();
+        let sibling = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 128), 25).unwrap();
+        assert_eq!(Some(sibling), ip_network.sibling());
+        assert_eq!(Some(ip_network), sibling.sibling());
+        assert_eq!(ip_network.supernet(), sibling.supernet());
+
+        assert_eq!(
+            Some(Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 32).unwrap()),
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 1), 32)
+                .unwrap()
+                .sibling()
+        );
+        assert_eq!(
+            Some(Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 1).unwrap()),
+            Ipv4Network::new(Ipv4Addr::new(128, 0, 0, 0), 1)
+                .unwrap()
+                .sibling()
+        );
+        assert_eq!(None, Ipv4Network::DEFAULT_ROUTE.sibling());
+    }
+
+    #[test]
+    fn first_and_last_address() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        assert_eq!(ip_network.network_address(), ip_network.first_address());
+        assert_eq!(ip_network.broadcast_address(), ip_network.last_address());
+        assert_eq!(Ipv4Addr::new(10, 255, 255, 255), ip_network.last_address());
+    }
+
+    #[test]
+    fn full_netmask_edge_netmasks() {
+        assert_eq!(
+            Ipv4Addr::new(0, 0, 0, 0),
+            Ipv4Network::DEFAULT_ROUTE.full_netmask()
+        );
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32).unwrap();
+        assert_eq!(Ipv4Addr::new(255, 255, 255, 255), ip_network.full_netmask());
+    }
+
+    #[test]
+    fn num_addresses() {
+        assert_eq!(1 << 32, Ipv4Network::DEFAULT_ROUTE.num_addresses());
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        assert_eq!(1 << 24, ip_network.num_addresses());
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 31).unwrap();
+        assert_eq!(2, ip_network.num_addresses());
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32).unwrap();
+        assert_eq!(1, ip_network.num_addresses());
+    }
+
+    #[test]
+    fn nth_address() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        assert_eq!(Some(Ipv4Addr::new(10, 0, 0, 0)), ip_network.nth_address(0));
+        assert_eq!(
+            Some(Ipv4Addr::new(10, 1, 0, 1)),
+            ip_network.nth_address(65537)
+        );
+        assert_eq!(
+            Some(Ipv4Addr::new(10, 255, 255, 255)),
+            ip_network.nth_address((1 << 24) - 1)
+        );
+        assert_eq!(None, ip_network.nth_address(1 << 24));
+
+        assert_eq!(
+            Some(Ipv4Addr::new(255, 255, 255, 255)),
+            Ipv4Network::DEFAULT_ROUTE.nth_address(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn hostmask() {
+        assert_eq!(
+            Ipv4Addr::new(255, 255, 255, 255),
+            Ipv4Network::DEFAULT_ROUTE.hostmask()
+        );
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        assert_eq!(Ipv4Addr::new(0, 255, 255, 255), ip_network.hostmask());
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32).unwrap();
+        assert_eq!(Ipv4Addr::new(0, 0, 0, 0), ip_network.hostmask());
+    }
+
+    #[test]
+    fn broadcast_address_edge_netmasks() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 1), 32).unwrap();
+        assert_eq!(
+            Ipv4Addr::new(192, 168, 1, 1),
+            ip_network.broadcast_address()
+        );
+
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 0).unwrap();
+        assert_eq!(
+            Ipv4Addr::new(255, 255, 255, 255),
+            ip_network.broadcast_address()
+        );
+    }
+
+    #[test]
+    fn contains_str() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!(Ok(true), ip_network.contains_str("192.168.1.200"));
+        assert_eq!(Ok(false), ip_network.contains_str("192.168.2.1"));
+        assert_eq!(
+            "192.168.1".parse::<Ipv4Addr>().unwrap_err(),
+            ip_network.contains_str("192.168.1").unwrap_err()
+        );
+        assert!(ip_network.contains_str("::1").is_err());
+    }
+
+    #[test]
+    fn mask() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+        assert_eq!(
+            Ipv4Addr::new(10, 0, 0, 0),
+            &ip_network & Ipv4Addr::new(10, 0, 0, 200)
+        );
+        assert_eq!(
+            Ipv4Addr::new(192, 168, 5, 0),
+            ip_network.mask(Ipv4Addr::new(192, 168, 5, 5))
+        );
+        assert_eq!(
+            Ipv4Addr::new(0, 0, 0, 0),
+            &Ipv4Network::DEFAULT_ROUTE & Ipv4Addr::new(192, 168, 5, 5)
+        );
+    }
+
+    #[test]
+    fn registry_name() {
+        let registry_name = |ip, netmask| Ipv4Network::new(ip, netmask).unwrap().registry_name();
+
+        assert_eq!(
+            Some("This network"),
+            registry_name(Ipv4Addr::new(0, 0, 0, 0), 8)
+        );
+        assert_eq!(
+            Some("Private-Use"),
+            registry_name(Ipv4Addr::new(172, 16, 0, 0), 12)
+        );
+        assert_eq!(
+            Some("Private-Use"),
+            registry_name(Ipv4Addr::new(192, 168, 1, 0), 24)
+        );
+        assert_eq!(
+            Some("Shared Address Space"),
+            registry_name(Ipv4Addr::new(100, 64, 0, 0), 10)
+        );
+        assert_eq!(
+            Some("Loopback"),
+            registry_name(Ipv4Addr::new(127, 0, 0, 0), 8)
+        );
+        assert_eq!(
+            Some("Link Local"),
+            registry_name(Ipv4Addr::new(169, 254, 1, 0), 24)
+        );
+        assert_eq!(
+            Some("IETF Protocol Assignments"),
+            registry_name(Ipv4Addr::new(192, 0, 0, 0), 24)
+        );
+        assert_eq!(
+            Some("Documentation (RFC 5737)"),
+            registry_name(Ipv4Addr::new(198, 51, 100, 0), 24)
+        );
+        assert_eq!(
+            Some("Benchmarking"),
+            registry_name(Ipv4Addr::new(198, 18, 0, 0), 15)
+        );
+        assert_eq!(
+            Some("AS112"),
+            registry_name(Ipv4Addr::new(192, 31, 196, 0), 24)
+        );
+        assert_eq!(
+            Some("Limited Broadcast"),
+            registry_name(Ipv4Addr::new(255, 255, 255, 255), 32)
+        );
+        assert_eq!(
+            Some("Reserved"),
+            registry_name(Ipv4Addr::new(240, 0, 0, 0), 4)
+        );
+
+        assert_eq!(None, registry_name(Ipv4Addr::new(8, 8, 8, 0), 24));
+        assert_eq!(None, registry_name(Ipv4Addr::new(10, 0, 0, 0), 7));
+    }
+
+    #[test]
+    fn is_as112() {
+        let is_as112 = |ip, netmask| Ipv4Network::new(ip, netmask).unwrap().is_as112();
+
+        assert!(is_as112(Ipv4Addr::new(192, 175, 48, 0), 24));
+        assert!(is_as112(Ipv4Addr::new(192, 175, 48, 6), 32));
+        assert!(!is_as112(Ipv4Addr::new(192, 175, 0, 0), 16));
+
+        assert!(is_as112(Ipv4Addr::new(192, 31, 196, 0), 24));
+        assert!(is_as112(Ipv4Addr::new(192, 31, 196, 1), 32));
+        assert!(!is_as112(Ipv4Addr::new(192, 31, 197, 0), 24));
+
+        assert!(!is_as112(Ipv4Addr::new(192, 168, 0, 0), 24));
+    }
+
     #[test]
     fn is_global() {
         let is_global = |ip, netmask| Ipv4Network::new(ip, netmask).unwrap().is_global();
@@ -1085,6 +3478,15 @@ mod tests {
         assert!(!is_global(Ipv4Addr::new(192, 0, 0, 0), 24)); // IETF Protocol Assignments
     }
 
+    #[test]
+    fn stable_hash() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        assert_eq!(0x6391_2782_0a46_0395, ip_network.stable_hash());
+
+        let other = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 9).unwrap();
+        assert_ne!(ip_network.stable_hash(), other.stable_hash());
+    }
+
     #[test]
     fn hash_same_for_same_input() {
         use std::hash::{Hash, Hasher};
@@ -1278,6 +3680,177 @@ mod tests {
         assert_eq!(2, collapsed.len());
     }
 
+    #[test]
+    fn collapse_addresses_deterministic() {
+        let mut addresses = vec![
+            Ipv4Network::from_str("10.0.0.0/24").unwrap(),
+            Ipv4Network::from_str("10.0.1.0/24").unwrap(),
+            Ipv4Network::from_str("10.0.2.0/25").unwrap(),
+            Ipv4Network::from_str("10.0.0.0/23").unwrap(),
+            Ipv4Network::from_str("192.168.0.0/16").unwrap(),
+            Ipv4Network::from_str("192.168.1.0/24").unwrap(),
+            Ipv4Network::from_str("1.1.1.1/32").unwrap(),
+            Ipv4Network::from_str("1.1.1.0/32").unwrap(),
+        ];
+        let expected = vec![
+            Ipv4Network::from_str("1.1.1.0/31").unwrap(),
+            Ipv4Network::from_str("10.0.0.0/23").unwrap(),
+            Ipv4Network::from_str("10.0.2.0/25").unwrap(),
+            Ipv4Network::from_str("192.168.0.0/16").unwrap(),
+        ];
+
+        for _ in 0..addresses.len() {
+            addresses.rotate_left(1);
+            assert_eq!(expected, Ipv4Network::collapse_addresses(&addresses));
+            addresses.reverse();
+            assert_eq!(expected, Ipv4Network::collapse_addresses(&addresses));
+        }
+    }
+
+    #[test]
+    fn aggregate_with_metadata() {
+        let aggregated = Ipv4Network::aggregate_with_metadata(
+            vec![
+                (Ipv4Network::from_str("10.0.0.128/25").unwrap(), 2),
+                (Ipv4Network::from_str("10.0.0.0/25").unwrap(), 3),
+            ],
+            |a, b| a + b,
+        );
+        assert_eq!(
+            vec![(Ipv4Network::from_str("10.0.0.0/24").unwrap(), 5)],
+            aggregated
+        );
+    }
+
+    #[test]
+    fn aggregate_with_metadata_same_as_collapse_addresses() {
+        let networks = vec![
+            Ipv4Network::from_str("10.0.0.0/24").unwrap(),
+            Ipv4Network::from_str("10.0.1.0/24").unwrap(),
+            Ipv4Network::from_str("10.0.2.0/25").unwrap(),
+            Ipv4Network::from_str("10.0.0.0/23").unwrap(),
+            Ipv4Network::from_str("10.0.3.0/24").unwrap(),
+            Ipv4Network::from_str("10.0.2.128/25").unwrap(),
+            Ipv4Network::from_str("192.168.0.0/16").unwrap(),
+            Ipv4Network::from_str("192.168.1.0/24").unwrap(),
+            Ipv4Network::from_str("1.1.1.1/32").unwrap(),
+            Ipv4Network::from_str("1.1.1.0/32").unwrap(),
+            Ipv4Network::from_str("1.1.1.0/32").unwrap(),
+        ];
+
+        let aggregated = Ipv4Network::aggregate_with_metadata(
+            networks.iter().map(|network| (*network, 1)).collect(),
+            |a, b| a + b,
+        );
+        assert_eq!(
+            Ipv4Network::collapse_addresses(&networks),
+            aggregated
+                .iter()
+                .map(|(network, _)| *network)
+                .collect::<Vec<_>>()
+        );
+        // Every input network is counted exactly once
+        assert_eq!(
+            vec![3, 6, 2],
+            aggregated
+                .iter()
+                .map(|(_, count)| *count)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn free_subnets() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        let free = ip_network.free_subnets(&[
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 128), 26).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 26).unwrap(),
+        ]);
+        assert_eq!(
+            vec![
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 64), 26).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 192), 26).unwrap(),
+            ],
+            free
+        );
+    }
+
+    #[test]
+    fn free_subnets_edge_cases() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+        assert_eq!(vec![ip_network], ip_network.free_subnets(&[]));
+        assert_eq!(
+            vec![ip_network],
+            ip_network.free_subnets(&[Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap()])
+        );
+        assert!(ip_network
+            .free_subnets(&[Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap()])
+            .is_empty());
+        assert_eq!(
+            vec![Ipv4Network::new(Ipv4Addr::new(128, 0, 0, 0), 1).unwrap()],
+            Ipv4Network::DEFAULT_ROUTE.free_subnets(&[Ipv4Network::new(
+                Ipv4Addr::new(0, 0, 0, 0),
+                1
+            )
+            .unwrap()])
+        );
+        assert_eq!(
+            vec![Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 1).unwrap()],
+            Ipv4Network::DEFAULT_ROUTE.free_subnets(&[Ipv4Network::new(
+                Ipv4Addr::new(128, 0, 0, 0),
+                1
+            )
+            .unwrap()])
+        );
+    }
+
+    #[test]
+    fn split_around() {
+        let ip_network = Ipv4Network::from_str("192.168.1.0/24").unwrap();
+        let (before, after) = ip_network
+            .split_around(Ipv4Addr::new(192, 168, 1, 128))
+            .unwrap();
+
+        assert_eq!(
+            before,
+            vec![Ipv4Network::from_str("192.168.1.0/25").unwrap()]
+        );
+        assert_eq!(
+            after,
+            vec![
+                Ipv4Network::from_str("192.168.1.129/32").unwrap(),
+                Ipv4Network::from_str("192.168.1.130/31").unwrap(),
+                Ipv4Network::from_str("192.168.1.132/30").unwrap(),
+                Ipv4Network::from_str("192.168.1.136/29").unwrap(),
+                Ipv4Network::from_str("192.168.1.144/28").unwrap(),
+                Ipv4Network::from_str("192.168.1.160/27").unwrap(),
+                Ipv4Network::from_str("192.168.1.192/26").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_around_first_address() {
+        let ip_network = Ipv4Network::from_str("192.168.1.0/31").unwrap();
+        let (before, after) = ip_network
+            .split_around(Ipv4Addr::new(192, 168, 1, 0))
+            .unwrap();
+
+        assert!(before.is_empty());
+        assert_eq!(
+            after,
+            vec![Ipv4Network::from_str("192.168.1.1/32").unwrap()]
+        );
+    }
+
+    #[test]
+    fn split_around_not_contained() {
+        let ip_network = Ipv4Network::from_str("192.168.1.0/24").unwrap();
+        assert!(ip_network
+            .split_around(Ipv4Addr::new(192, 168, 2, 0))
+            .is_none());
+    }
+
     #[test]
     fn from_ipv4addr() {
         let ip = Ipv4Addr::new(127, 0, 0, 1);
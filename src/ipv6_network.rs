@@ -1,12 +1,12 @@
 use std::fmt;
-use std::net::Ipv6Addr;
+use std::net::{AddrParseError, Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 use std::hash::{Hash, Hasher};
-use crate::{IpNetworkError, IpNetworkParseError};
+use crate::{Contained, DisplayHost, IpNetworkError, IpNetworkParseError, Ipv4Network};
 use crate::helpers;
 use crate::iterator;
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
 
 /// IPv6 Multicast Address Scopes.
 #[derive(Copy, PartialEq, Eq, Clone, Hash, Debug)]
@@ -20,6 +20,23 @@ pub enum Ipv6MulticastScope {
     Global,
 }
 
+/// IPv6 Multicast Address Flags.
+///
+/// These flags are defined in [IETF RFC 4291], [IETF RFC 3306] and [IETF RFC 3956].
+///
+/// [IETF RFC 4291]: https://tools.ietf.org/html/rfc4291
+/// [IETF RFC 3306]: https://tools.ietf.org/html/rfc3306
+/// [IETF RFC 3956]: https://tools.ietf.org/html/rfc3956
+#[derive(Copy, PartialEq, Eq, Clone, Hash, Debug)]
+pub struct Ipv6MulticastFlags {
+    /// T flag, multicast address is not permanently assigned (transient).
+    pub transient: bool,
+    /// P flag, multicast address is assigned based on network prefix.
+    pub prefix_based: bool,
+    /// R flag, multicast address has embedded rendezvous point address.
+    pub rendezvous_point: bool,
+}
+
 /// IPv6 Network.
 #[derive(Clone, Copy, Debug, Eq, PartialOrd, Ord)]
 pub struct Ipv6Network {
@@ -106,6 +123,99 @@ impl Ipv6Network {
         })
     }
 
+    /// Constructs new `Ipv6Network` from value packed by [`to_packed`] method.
+    ///
+    /// Returns error if netmask is bigger than 128 or when host bits are set in network address.
+    ///
+    /// [`to_packed`]: #method.to_packed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::from_packed((0x2001_0db8 << 96, 32))?;
+    /// assert_eq!(ip_network, Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn from_packed(packed: (u128, u8)) -> Result<Self, IpNetworkError> {
+        Self::new(Ipv6Addr::from(packed.0), packed.1)
+    }
+
+    /// Returns network packed to tuple of network address as `u128` and netmask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?;
+    /// assert_eq!((0x2001_0db8 << 96, 32), ip_network.to_packed());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn to_packed(&self) -> (u128, u8) {
+        (u128::from(self.network_address), self.netmask)
+    }
+
+    /// Converts IPv4-compatible (::a.b.c.d) or IPv4-mapped (::ffff:a.b.c.d) network to
+    /// `Ipv4Network` with netmask smaller by 96. Returns `None` for other networks or when
+    /// netmask is smaller than 96.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::{Ipv4Addr, Ipv6Addr};
+    /// use ip_network::{Ipv4Network, Ipv6Network};
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0xc0a8, 0x100), 120)?;
+    /// assert_eq!(ip_network.to_ipv4(), Some(Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24)?));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn to_ipv4(&self) -> Option<Ipv4Network> {
+        if self.netmask < 96 {
+            return None;
+        }
+
+        self.network_address
+            .to_ipv4()
+            .map(|network_address| Ipv4Network {
+                network_address,
+                netmask: self.netmask - 96,
+            })
+    }
+
+    /// Returns IPv4 address embedded in network address at given bit offset (32 bits starting at
+    /// `offset_bits`). Returns `None` when embedded address is not fully covered by network
+    /// prefix (`offset_bits + 32` is bigger than netmask).
+    ///
+    /// For example IPv4-mapped addresses ([IETF RFC 4291]) contains IPv4 address at offset 96 and
+    /// 6to4 addresses ([IETF RFC 3056]) at offset 16.
+    ///
+    /// [IETF RFC 4291]: https://tools.ietf.org/html/rfc4291#section-2.5.5.2
+    /// [IETF RFC 3056]: https://tools.ietf.org/html/rfc3056#section-2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::{Ipv4Addr, Ipv6Addr};
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2002, 0xc0a8, 0x101, 0, 0, 0, 0, 0), 48)?;
+    /// assert_eq!(ip_network.embedded_ipv4_at(16), Some(Ipv4Addr::new(192, 168, 1, 1)));
+    /// assert_eq!(ip_network.embedded_ipv4_at(96), None);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn embedded_ipv4_at(&self, offset_bits: u8) -> Option<Ipv4Addr> {
+        if u16::from(offset_bits) + 32 > u16::from(self.netmask) {
+            return None;
+        }
+
+        let address = u128::from(self.network_address) << offset_bits >> 96;
+        Some(Ipv4Addr::from(address as u32))
+    }
+
     /// Returns network IP address (first address in range).
     ///
     /// # Examples
@@ -124,6 +234,120 @@ impl Ipv6Network {
         self.network_address
     }
 
+    /// Returns octets of network IP address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?;
+    /// assert_eq!(ip_network.octets(), [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub fn octets(&self) -> [u8; 16] {
+        self.network_address.octets()
+    }
+
+    /// Returns octets of network IP address with all bits after netmask set to zero. Useful as
+    /// key for hashing or maps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?;
+    /// assert_eq!(ip_network.prefix_bytes(), [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub fn prefix_bytes(&self) -> [u8; 16] {
+        (u128::from(self.network_address) & helpers::bite_mask_u128(self.netmask)).to_be_bytes()
+    }
+
+    /// Returns object implementing `Display` that formats network without netmask when netmask
+    /// is 128 (network contains just one address), otherwise in CIDR notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 128)?;
+    /// assert_eq!(ip_network.display_host().to_string(), "2001:db8::1");
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub fn display_host(&self) -> DisplayHost<'_, Self> {
+        DisplayHost::new(self)
+    }
+
+    /// Writes network in format X:X::X/Y (CIDR notation) to any `core::fmt::Write` implementation
+    /// without allocation, so it can be used also with fixed capacity buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let mut output = String::new();
+    /// Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?.write_cidr(&mut output)?;
+    /// assert_eq!(output, "2001:db8::/32");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_cidr<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        write!(w, "{}/{}", self.network_address, self.netmask)
+    }
+
+    /// Returns hash of network that doesn't depend on `RandomState` or platform, so it can be
+    /// stored in persistent structures. Hash is computed by 64-bit FNV-1a algorithm over
+    /// network address octets followed by netmask and is guaranteed to not change in future
+    /// versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?;
+    /// assert_eq!(ip_network.stable_hash(), 0x99c8_a50b_3c0c_cf85);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn stable_hash(&self) -> u64 {
+        let mut bytes = [0; 17];
+        bytes[..16].copy_from_slice(&self.network_address.octets());
+        bytes[16] = self.netmask;
+        helpers::fnv1a_64(&bytes)
+    }
+
+    /// Returns first IP address in range, same as [`network_address`].
+    ///
+    /// [`network_address`]: #method.network_address
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0);
+    /// let ip_network = Ipv6Network::new(ip, 32)?;
+    /// assert_eq!(ip_network.first_address(), ip);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub const fn first_address(&self) -> Ipv6Addr {
+        self.network_address
+    }
+
     /// Returns last IP address in range. Similar as `broadcast_address` for IPv4.
     ///
     /// # Examples
@@ -141,6 +365,90 @@ impl Ipv6Network {
         Ipv6Addr::from(u128::from(self.network_address) | !helpers::bite_mask_u128(self.netmask))
     }
 
+    /// Returns number of addresses in network. For netmask 0 the real count is 2^128, which
+    /// doesn't fit into `u128`, so this method returns `0` in that case. Use
+    /// [`checked_num_addresses`] to handle this case explicitly.
+    ///
+    /// [`checked_num_addresses`]: #method.checked_num_addresses
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 120)?;
+    /// assert_eq!(ip_network.num_addresses(), 256);
+    /// assert_eq!(Ipv6Network::DEFAULT_ROUTE.num_addresses(), 0);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub fn num_addresses(&self) -> u128 {
+        1u128
+            .checked_shl(u32::from(Self::LENGTH - self.netmask))
+            .unwrap_or(0)
+    }
+
+    /// Returns number of addresses in network or `None` for netmask 0, when the count (2^128)
+    /// doesn't fit into `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 120)?;
+    /// assert_eq!(ip_network.checked_num_addresses(), Some(256));
+    /// assert_eq!(Ipv6Network::DEFAULT_ROUTE.checked_num_addresses(), None);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub fn checked_num_addresses(&self) -> Option<u128> {
+        1u128.checked_shl(u32::from(Self::LENGTH - self.netmask))
+    }
+
+    /// Returns `n`-th IP address in network (counted from network address) or `None` when `n` is
+    /// not smaller than number of addresses in network. Unlike iterating, this is constant time
+    /// operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 120)?;
+    /// assert_eq!(ip_network.nth_address(10), Some(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 10)));
+    /// assert_eq!(ip_network.nth_address(256), None);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn nth_address(&self, n: u128) -> Option<Ipv6Addr> {
+        match self.checked_num_addresses() {
+            Some(count) if n >= count => None,
+            _ => Some(Ipv6Addr::from(u128::from(self.network_address) | n)),
+        }
+    }
+
+    /// Returns [`true`] if network contains just one address (netmask is 128).
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 128)?;
+    /// assert!(ip_network.is_single_address());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub const fn is_single_address(&self) -> bool {
+        self.netmask == Self::LENGTH
+    }
+
     /// Returns network mask.
     ///
     /// # Examples
@@ -159,7 +467,41 @@ impl Ipv6Network {
         self.netmask
     }
 
-    /// Returns [`true`] if given [`IPv6Addr`] is inside this network.
+    /// Returns number of network bits, that is the same value as netmask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 64)?;
+    /// assert_eq!(ip_network.network_bits(), 64);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub fn network_bits(&self) -> u8 {
+        self.netmask
+    }
+
+    /// Returns number of host bits, that is 128 minus netmask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 64)?;
+    /// assert_eq!(ip_network.host_bits(), 64);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub fn host_bits(&self) -> u8 {
+        Self::LENGTH - self.netmask
+    }
+
+    /// Returns [`true`] if given [`IPv6Addr`] or `Ipv6Network` is inside this network.
     ///
     /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
     /// [`Ipv6Addr`]: https://doc.rust-lang.org/std/net/struct.Ipv6Addr.html
@@ -173,11 +515,75 @@ impl Ipv6Network {
     /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 64)?;
     /// assert!(ip_network.contains(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)));
     /// assert!(!ip_network.contains(Ipv6Addr::new(0x2001, 0xdb9, 0, 0, 0, 0, 0, 0)));
+    /// assert!(ip_network.contains(Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 96)?));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub fn contains<T: Contained<Self>>(&self, other: T) -> bool {
+        other.is_contained_in(self)
+    }
+
+    /// Returns [`true`] if given [`IPv6Addr`] is inside this network. Unlike [`contains`], this
+    /// method can be used in `const` context.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// [`Ipv6Addr`]: https://doc.rust-lang.org/std/net/struct.Ipv6Addr.html
+    /// [`contains`]: #method.contains
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 64)?;
+    /// assert!(ip_network.contains_address(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub const fn contains_address(&self, ip: Ipv6Addr) -> bool {
+        if self.netmask == 0 {
+            return true;
+        }
+
+        let truncated_ip = u128::from_be_bytes(ip.octets()) & helpers::bite_mask_u128(self.netmask);
+        truncated_ip == u128::from_be_bytes(self.network_address.octets())
+    }
+
+    /// Returns [`true`] if given network is inside this network (or is the same network).
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?;
+    /// assert!(ip_network.contains_network(&Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 1, 0, 0, 0, 0, 0), 48)?));
+    /// assert!(!ip_network.contains_network(&Ipv6Network::new(Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 16)?));
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn contains(&self, ip: Ipv6Addr) -> bool {
-        let truncated_ip = u128::from(ip) & helpers::bite_mask_u128(self.netmask);
-        truncated_ip == u128::from(self.network_address)
+    pub const fn contains_network(&self, other: &Ipv6Network) -> bool {
+        other.netmask >= self.netmask && self.contains_address(other.network_address)
+    }
+
+    /// Parses given string as IP address and returns [`true`] if it is inside this network.
+    /// Returns error when string is not valid IPv6 address.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network: Ipv6Network = "2001:db8::/32".parse().unwrap();
+    /// assert_eq!(ip_network.contains_str("2001:db8::1"), Ok(true));
+    /// assert!(ip_network.contains_str("2001:db8::g").is_err());
+    /// ```
+    pub fn contains_str(&self, ip: &str) -> Result<bool, AddrParseError> {
+        Ipv6Addr::from_str(ip).map(|ip| self.contains_address(ip))
     }
 
     /// Returns network with smaller netmask by one. If netmask is already zero, `None` will be returned.
@@ -200,6 +606,31 @@ impl Ipv6Network {
         }
     }
 
+    /// Returns iterator over all supernets of this network, from network with netmask smaller by
+    /// one to network with netmask 0 (including). This is the same as repeatedly calling
+    /// [`supernet`] method.
+    ///
+    /// [`supernet`]: #method.supernet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?;
+    /// let mut iterator = network.supernets();
+    /// assert_eq!(iterator.next().unwrap(), Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 31)?);
+    /// assert_eq!(iterator.last().unwrap(), Ipv6Network::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0), 0)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn supernets(&self) -> impl ExactSizeIterator<Item = Ipv6Network> {
+        let network_address = self.network_address;
+        (0..self.netmask)
+            .rev()
+            .map(move |netmask| Self::new_truncate(network_address, netmask).unwrap())
+    }
+
     /// Returns `Ipv6NetworkIterator` over networks with netmask bigger one.
     /// If netmask is already 128, empty iterator will be returned.
     ///
@@ -216,10 +647,72 @@ impl Ipv6Network {
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
     pub fn subnets(&self) -> iterator::Ipv6NetworkIterator {
-        let new_netmask = ::std::cmp::min(self.netmask + 1, Self::LENGTH);
+        let new_netmask = std::cmp::min(self.netmask + 1, Self::LENGTH);
         iterator::Ipv6NetworkIterator::new(*self, new_netmask)
     }
 
+    /// Returns two subnets with netmask bigger by one, that together form this network. For
+    /// network with netmask 128 returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?;
+    /// let (first, second) = ip_network.halves().unwrap();
+    /// assert_eq!(first, Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 33)?);
+    /// assert_eq!(second, Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0x8000, 0, 0, 0, 0, 0), 33)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn halves(&self) -> Option<(Self, Self)> {
+        if self.netmask == Self::LENGTH {
+            return None;
+        }
+
+        let netmask = self.netmask + 1;
+        let second = u128::from(self.network_address) | (1 << (Self::LENGTH - netmask));
+        Some((
+            Self {
+                network_address: self.network_address,
+                netmask,
+            },
+            Self {
+                network_address: Ipv6Addr::from(second),
+                netmask,
+            },
+        ))
+    }
+
+    /// Returns the other half of [`supernet`], network with the same netmask that shares parent
+    /// network with this network. For network with netmask 0 returns `None`.
+    ///
+    /// [`supernet`]: #method.supernet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 33)?;
+    /// assert_eq!(ip_network.sibling(), Some(Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0x8000, 0, 0, 0, 0, 0), 33)?));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn sibling(&self) -> Option<Self> {
+        if self.netmask == 0 {
+            return None;
+        }
+
+        let network_address =
+            u128::from(self.network_address) ^ (1 << (Self::LENGTH - self.netmask));
+        Some(Self {
+            network_address: Ipv6Addr::from(network_address),
+            netmask: self.netmask,
+        })
+    }
+
     /// Returns `Ipv6NetworkIterator` over networks with defined netmask. Because [`len()`] method
     /// returns `usize` and number of networks can be bigger than `usize`, you can use `real_len()` method
     /// to get exact number of networks.
@@ -260,10 +753,30 @@ impl Ipv6Network {
     /// assert!(Ipv6Network::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0), 0)?.is_default_route());
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
-    pub fn is_default_route(&self) -> bool {
+    pub const fn is_default_route(&self) -> bool {
         self.netmask == 0
     }
 
+    /// Returns [`true`] for the default route network (::/0), same as [`is_default_route`].
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    /// [`is_default_route`]: #method.is_default_route
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// assert!(Ipv6Network::new(Ipv6Addr::UNSPECIFIED, 0)?.is_default());
+    /// assert!(!Ipv6Network::new(Ipv6Addr::UNSPECIFIED, 128)?.is_default());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    #[inline]
+    pub const fn is_default(&self) -> bool {
+        self.is_default_route()
+    }
+
     /// Returns [`true`] for the special 'unspecified' network (::/128).
     ///
     /// This property is defined in [IETF RFC 4291].
@@ -313,6 +826,7 @@ impl Ipv6Network {
     /// - the loopback network
     /// - link-local, site-local, and unique local unicast networks
     /// - interface-, link-, realm-, admin- and site-local multicast networks
+    /// - the discard-only network
     ///
     /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
     /// [`false`]: https://doc.rust-lang.org/std/primitive.bool.html
@@ -418,6 +932,28 @@ impl Ipv6Network {
         segments[0] == 0x2001 && segments[1] == 0xdb8 && self.netmask >= 32
     }
 
+    /// Returns [`true`] if this is a part of discard-only network (100::/64).
+    ///
+    /// This property is defined in [IETF RFC 6666].
+    ///
+    /// [IETF RFC 6666]: https://tools.ietf.org/html/rfc6666
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// assert!(Ipv6Network::new(Ipv6Addr::new(0x100, 0, 0, 0, 0, 0, 0, 0), 64)?.is_discard_only());
+    /// assert!(!Ipv6Network::new(Ipv6Addr::new(0x100, 0, 0, 0, 0, 0, 0, 0), 63)?.is_discard_only());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn is_discard_only(&self) -> bool {
+        let segments = self.network_address.segments();
+        segments[..4] == [0x100, 0, 0, 0] && self.netmask >= 64
+    }
+
     /// Returns [`true`] if the network is a globally routable unicast network.
     ///
     /// The following return [`false`]:
@@ -428,6 +964,7 @@ impl Ipv6Network {
     /// - unique local network
     /// - the unspecified network
     /// - the network range reserved for documentation
+    /// - the discard-only network
     ///
     /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
     /// [`false`]: https://doc.rust-lang.org/std/primitive.bool.html
@@ -450,6 +987,48 @@ impl Ipv6Network {
             && !self.is_unique_local()
             && !self.is_unspecified()
             && !self.is_documentation()
+            && !self.is_discard_only()
+    }
+
+    /// Returns name of block from [IANA IPv6 Special-Purpose Address Registry][ipv6-sr] that
+    /// contains this whole network, or `None` when network is not part of any special-purpose
+    /// block known to this crate.
+    ///
+    /// [ipv6-sr]: https://www.iana.org/assignments/iana-ipv6-special-registry/iana-ipv6-special-registry.xhtml
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// assert_eq!(Some("Documentation"), Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?.registry_name());
+    /// assert_eq!(Some("Loopback Address"), Ipv6Network::new(Ipv6Addr::LOCALHOST, 128)?.registry_name());
+    /// assert_eq!(None, Ipv6Network::new(Ipv6Addr::new(0x2a00, 0x1450, 0, 0, 0, 0, 0, 0), 32)?.registry_name());
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn registry_name(&self) -> Option<&'static str> {
+        let segments = self.network_address.segments();
+        let name = if self.is_unspecified() {
+            "Unspecified Address"
+        } else if self.is_loopback() {
+            "Loopback Address"
+        } else if self.netmask >= 96 && segments[..6] == [0, 0, 0, 0, 0, 0xffff] {
+            "IPv4-mapped Address"
+        } else if self.is_discard_only() {
+            "Discard-Only Address Block"
+        } else if self.is_documentation() {
+            "Documentation"
+        } else if self.is_unique_local() {
+            "Unique-Local"
+        } else if self.is_unicast_link_local() {
+            "Link-Local Unicast"
+        } else if self.is_unicast_site_local() {
+            "Site-Local (deprecated)"
+        } else {
+            return None;
+        };
+        Some(name)
     }
 
     /// Returns [`true`] if this is a part of multicast network (ff00::/8).
@@ -507,7 +1086,81 @@ impl Ipv6Network {
         }
     }
 
+    /// Returns the network's multicast flags if the network is multicast and its netmask covers
+    /// the whole flags field (netmask is at least 12). For shorter netmask (like `ff00::/8`) flags
+    /// are not defined by the network and `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::{Ipv6Network, Ipv6MulticastFlags};
+    ///
+    /// assert_eq!(Ipv6Network::new(Ipv6Addr::new(0xff3e, 0, 0, 0, 0, 0, 0, 0), 16)?.multicast_flags(),
+    ///            Some(Ipv6MulticastFlags { transient: true, prefix_based: true, rendezvous_point: false }));
+    /// assert_eq!(Ipv6Network::new(Ipv6Addr::new(0xff00, 0, 0, 0, 0, 0, 0, 0), 8)?.multicast_flags(), None);
+    /// assert_eq!(Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?.multicast_flags(), None);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn multicast_flags(&self) -> Option<Ipv6MulticastFlags> {
+        if self.is_multicast() && self.netmask >= 12 {
+            let flags = (self.network_address.segments()[0] >> 4) & 0xf;
+            Some(Ipv6MulticastFlags {
+                transient: flags & 0x1 != 0,
+                prefix_based: flags & 0x2 != 0,
+                rendezvous_point: flags & 0x4 != 0,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns solicited-node multicast address (as network with netmask 128) for given unicast
+    /// address. It is created from prefix ff02::1:ff00:0/104 and the low 24 bits of `ip`.
+    ///
+    /// This address is defined by [IETF RFC 4291].
+    ///
+    /// [IETF RFC 4291]: https://tools.ietf.org/html/rfc4291#section-2.7.1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0x1234, 0x5678);
+    /// assert_eq!(Ipv6Network::solicited_node_multicast(ip), Ipv6Network::new(Ipv6Addr::new(0xff02, 0, 0, 0, 0, 1, 0xff34, 0x5678), 128)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn solicited_node_multicast(ip: Ipv6Addr) -> Self {
+        const PREFIX: u128 = 0xff02_0000_0000_0000_0000_0001_ff00_0000;
+
+        Self {
+            network_address: Ipv6Addr::from(PREFIX | u128::from(ip) & 0xff_ffff),
+            netmask: Self::LENGTH,
+        }
+    }
+
+    /// Converts `Ipv6Network` to string in format X:X::X/Y (CIDR notation) with uppercase
+    /// hexadecimal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use ip_network::Ipv6Network;
+    ///
+    /// let ip_network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)?;
+    /// assert_eq!(ip_network.to_string_uppercase(), "2001:DB8::/32");
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn to_string_uppercase(&self) -> String {
+        // Only hexadecimal digits can be lowercase letters in IPv6 address
+        self.to_string().to_ascii_uppercase()
+    }
+
     /// Converts string in format X:X::X/Y (CIDR notation) to `Ipv6Network`, but truncating host bits.
+    /// Plain IP address without netmask is converted to network with netmask 128.
     ///
     /// # Examples
     ///
@@ -522,18 +1175,23 @@ impl Ipv6Network {
     /// ```
     pub fn from_str_truncate(s: &str) -> Result<Self, IpNetworkParseError> {
         let (ip, netmask) =
-            helpers::split_ip_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
+            helpers::split_ip_optional_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
 
         let network_address =
             Ipv6Addr::from_str(ip).map_err(|_| IpNetworkParseError::AddrParseError)?;
-        let netmask =
-            u8::from_str(netmask).map_err(|_| IpNetworkParseError::InvalidNetmaskFormat)?;
+        let netmask = match netmask {
+            Some(netmask) => helpers::parse_netmask(netmask, ip.len() + 1)?,
+            None => Self::LENGTH,
+        };
 
         Self::new_truncate(network_address, netmask).map_err(IpNetworkParseError::IpNetworkError)
     }
 
     /// Return an iterator of the collapsed Ipv6Networks.
     ///
+    /// Returned networks are sorted by network address and netmask and result doesn't depend on
+    /// order of given networks.
+    ///
     /// Implementation of this method was inspired by Python [`ipaddress.collapse_addresses`]
     ///
     /// [`ipaddress.collapse_addresses`]: https://docs.python.org/3/library/ipaddress.html#ipaddress.collapse_addresses
@@ -554,7 +1212,7 @@ impl Ipv6Network {
     /// # Ok::<(), ip_network::IpNetworkParseError>(())
     /// ```
     pub fn collapse_addresses(addresses: &[Self]) -> Vec<Self> {
-        let mut subnets = HashMap::new();
+        let mut subnets = BTreeMap::new();
 
         let mut to_merge = addresses.to_vec();
         while let Some(net) = to_merge.pop() {
@@ -603,14 +1261,22 @@ impl fmt::Display for Ipv6Network {
     /// # Ok::<(), ip_network::IpNetworkError>(())
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}/{}", self.network_address, self.netmask)
+        if f.width().is_none() && f.precision().is_none() {
+            write!(f, "{}/{}", self.network_address, self.netmask)
+        } else {
+            // Longest possible output is eight groups of four hex digits followed by `/128`
+            let mut buffer = helpers::FixedBuffer::<43>::new();
+            self.write_cidr(&mut buffer)?;
+            f.pad(buffer.as_str())
+        }
     }
 }
 
 impl FromStr for Ipv6Network {
     type Err = IpNetworkParseError;
 
-    /// Converts string in format X:X::X/Y (CIDR notation) to `Ipv6Network`.
+    /// Converts string in format X:X::X/Y (CIDR notation) to `Ipv6Network`. Plain IP address
+    /// without netmask is converted to network with netmask 128.
     ///
     /// # Examples
     ///
@@ -622,16 +1288,19 @@ impl FromStr for Ipv6Network {
     /// let ip_network = Ipv6Network::from_str("2001:db8::/32")?;
     /// assert_eq!(ip_network.network_address(), Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0));
     /// assert_eq!(ip_network.netmask(), 32);
+    /// assert_eq!(Ipv6Network::from_str("2001:db8::1")?.netmask(), 128);
     /// # Ok::<(), ip_network::IpNetworkParseError>(())
     /// ```
     fn from_str(s: &str) -> Result<Ipv6Network, IpNetworkParseError> {
         let (ip, netmask) =
-            helpers::split_ip_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
+            helpers::split_ip_optional_netmask(s).ok_or(IpNetworkParseError::InvalidFormatError)?;
 
         let network_address =
             Ipv6Addr::from_str(ip).map_err(|_| IpNetworkParseError::AddrParseError)?;
-        let netmask =
-            u8::from_str(netmask).map_err(|_| IpNetworkParseError::InvalidNetmaskFormat)?;
+        let netmask = match netmask {
+            Some(netmask) => helpers::parse_netmask(netmask, ip.len() + 1)?,
+            None => Self::LENGTH,
+        };
 
         Self::new(network_address, netmask).map_err(IpNetworkParseError::IpNetworkError)
     }
@@ -655,6 +1324,28 @@ impl PartialEq for Ipv6Network {
     }
 }
 
+impl PartialEq<Ipv6Addr> for Ipv6Network {
+    /// Returns [`true`] only when network has netmask 128 and its address is the same as `other`.
+    ///
+    /// [`true`]: https://doc.rust-lang.org/std/primitive.bool.html
+    #[inline]
+    fn eq(&self, other: &Ipv6Addr) -> bool {
+        self.netmask == Self::LENGTH && self.network_address == *other
+    }
+}
+
+impl PartialEq<Ipv6Network> for Ipv6Addr {
+    #[inline]
+    fn eq(&self, other: &Ipv6Network) -> bool {
+        other == self
+    }
+}
+
+/// Feeds network address and then netmask to given `Hasher`. Resulting value depends on `Hasher`
+/// and on how `std` hashes `Ipv6Addr`, so it can differ between program runs, platforms and Rust
+/// versions. For hash value that is guaranteed to not change, use [`Ipv6Network::stable_hash`].
+///
+/// [`Ipv6Network::stable_hash`]: struct.Ipv6Network.html#method.stable_hash
 impl Hash for Ipv6Network {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.network_address.hash(state);
@@ -664,8 +1355,12 @@ impl Hash for Ipv6Network {
 
 #[cfg(test)]
 mod tests {
-    use std::net::Ipv6Addr;
-    use crate::{Ipv6Network, IpNetworkError, Ipv6MulticastScope};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use crate::{
+        Ipv4Network, Ipv6Network, IpNetworkError, IpNetworkParseError, Ipv6MulticastFlags,
+        Ipv6MulticastScope,
+    };
+    use crate::helpers::FixedBuffer;
     use std::str::FromStr;
     use std::hash::{Hash, Hasher};
     use std::collections::hash_map::DefaultHasher;
@@ -699,6 +1394,103 @@ mod tests {
         assert_eq!(IpNetworkError::NetmaskError(129), network.unwrap_err());
     }
 
+    #[test]
+    fn eq_address() {
+        let ip = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        let ip_network = Ipv6Network::from(ip);
+        assert!(ip_network == ip);
+        assert!(ip == ip_network);
+        assert!(ip_network != Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0));
+    }
+
+    #[test]
+    fn eq_address_not_host_network() {
+        let ip = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0);
+        let ip_network = Ipv6Network::new(ip, 64).unwrap();
+        assert!(ip_network != ip);
+        assert!(ip != ip_network);
+    }
+
+    #[test]
+    fn num_addresses() {
+        let ip = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0);
+        let ip_network = Ipv6Network::new(ip, 127).unwrap();
+        assert_eq!(2, ip_network.num_addresses());
+        assert!(!ip_network.is_single_address());
+
+        let ip_network = Ipv6Network::new(ip, 128).unwrap();
+        assert_eq!(1, ip_network.num_addresses());
+        assert!(ip_network.is_single_address());
+
+        let ip_network = Ipv6Network::new(Ipv6Addr::new(0x8000, 0, 0, 0, 0, 0, 0, 0), 1).unwrap();
+        assert_eq!(1 << 127, ip_network.num_addresses());
+
+        // 2^128 doesn't fit into u128
+        assert_eq!(0, Ipv6Network::DEFAULT_ROUTE.num_addresses());
+    }
+
+    #[test]
+    fn nth_address() {
+        let ip_network =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 126).unwrap();
+        assert_eq!(
+            Some(ip_network.network_address()),
+            ip_network.nth_address(0)
+        );
+        assert_eq!(Some(ip_network.last_address()), ip_network.nth_address(3));
+        assert_eq!(None, ip_network.nth_address(4));
+
+        assert_eq!(
+            Some(Ipv6Addr::from(u128::MAX)),
+            Ipv6Network::DEFAULT_ROUTE.nth_address(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn checked_num_addresses() {
+        let ip_network =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 128).unwrap();
+        assert_eq!(Some(1), ip_network.checked_num_addresses());
+        let ip_network = Ipv6Network::new(Ipv6Addr::new(0x8000, 0, 0, 0, 0, 0, 0, 0), 1).unwrap();
+        assert_eq!(Some(1 << 127), ip_network.checked_num_addresses());
+        assert_eq!(None, Ipv6Network::DEFAULT_ROUTE.checked_num_addresses());
+    }
+
+    #[test]
+    fn prefix_bytes() {
+        let ip_network =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+        let bytes = ip_network.prefix_bytes();
+        assert_eq!(bytes[..4], [0x20, 0x01, 0x0d, 0xb8]);
+        assert!(bytes[4..].iter().all(|&byte| byte == 0));
+        assert_eq!(ip_network.octets(), bytes);
+    }
+
+    #[test]
+    fn octets() {
+        let ip_network =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+        assert_eq!(
+            [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            ip_network.octets()
+        );
+    }
+
+    #[test]
+    fn network_and_host_bits() {
+        let ip_network =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 64).unwrap();
+        assert_eq!(
+            (64, 64),
+            (ip_network.network_bits(), ip_network.host_bits())
+        );
+
+        assert_eq!(
+            0,
+            Ipv6Network::from(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)).host_bits()
+        );
+    }
+
     #[test]
     fn new_truncate_invalid_netmask() {
         let ip = Ipv6Addr::new(0xfc00, 0, 0, 0, 0, 0, 0, 0);
@@ -707,6 +1499,84 @@ mod tests {
         assert_eq!(IpNetworkError::NetmaskError(129), network.unwrap_err());
     }
 
+    #[test]
+    fn new_accepts_only_masked_addresses() {
+        crate::helpers::test_new_accepts_only_masked_addresses!(Ipv6Network, Ipv6Addr, u128);
+    }
+
+    #[test]
+    fn to_ipv4() {
+        let ip_network = Ipv6Network::from_str("::ffff:10.0.0.0/104").unwrap();
+        assert_eq!(
+            Some(Ipv4Network::from_str("10.0.0.0/8").unwrap()),
+            ip_network.to_ipv4()
+        );
+
+        let ip_network = Ipv6Network::from_str("::10.0.0.0/104").unwrap();
+        assert_eq!(
+            Some(Ipv4Network::from_str("10.0.0.0/8").unwrap()),
+            ip_network.to_ipv4()
+        );
+    }
+
+    #[test]
+    fn embedded_ipv4_at() {
+        let mapped = Ipv6Network::from_str("::ffff:192.168.1.1/128").unwrap();
+        assert_eq!(
+            Some(Ipv4Addr::new(192, 168, 1, 1)),
+            mapped.embedded_ipv4_at(96)
+        );
+        assert_eq!(None, mapped.embedded_ipv4_at(97));
+        assert_eq!(None, mapped.embedded_ipv4_at(255));
+
+        let six_to_four = Ipv6Network::from_str("2002:c0a8:101::/48").unwrap();
+        assert_eq!(
+            Some(Ipv4Addr::new(192, 168, 1, 1)),
+            six_to_four.embedded_ipv4_at(16)
+        );
+        assert_eq!(
+            Some(Ipv4Addr::new(32, 2, 192, 168)),
+            six_to_four.embedded_ipv4_at(0)
+        );
+        assert_eq!(None, six_to_four.embedded_ipv4_at(17));
+        assert_eq!(None, six_to_four.embedded_ipv4_at(96));
+    }
+
+    #[test]
+    fn to_ipv4_none() {
+        assert_eq!(None, Ipv6Network::from_str("::/64").unwrap().to_ipv4());
+        assert_eq!(
+            None,
+            Ipv6Network::from_str("2001:db8::/128").unwrap().to_ipv4()
+        );
+    }
+
+    #[test]
+    fn packed_round_trip() {
+        let networks = [
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap(),
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 128).unwrap(),
+        ];
+        for ip_network in &networks {
+            assert_eq!(
+                *ip_network,
+                Ipv6Network::from_packed(ip_network.to_packed()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn from_packed_invalid() {
+        assert_eq!(
+            IpNetworkError::NetmaskError(129),
+            Ipv6Network::from_packed((0, 129)).unwrap_err()
+        );
+        assert_eq!(
+            IpNetworkError::HostBitsSet,
+            Ipv6Network::from_packed((1, 64)).unwrap_err()
+        );
+    }
+
     #[test]
     fn contains() {
         let ip_network = return_test_ipv6_network();
@@ -720,6 +1590,15 @@ mod tests {
         assert!(!ip_network.contains(Ipv6Addr::new(0x2001, 0x0db9, 0, 0, 0, 0, 0, 0)));
     }
 
+    #[test]
+    fn contains_default_route() {
+        assert!(Ipv6Network::DEFAULT_ROUTE.contains(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0)));
+        assert!(Ipv6Network::DEFAULT_ROUTE.contains(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)));
+        assert!(Ipv6Network::DEFAULT_ROUTE.contains(Ipv6Addr::new(
+            0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff
+        )));
+    }
+
     #[test]
     fn supernet() {
         let ip_network = return_test_ipv6_network();
@@ -729,6 +1608,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn supernets() {
+        let ip_network =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1fc), 126).unwrap();
+        let supernets = ip_network.supernets().collect::<Vec<_>>();
+        assert_eq!(126, supernets.len());
+        assert_eq!(
+            supernets[..6],
+            [
+                Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1f8), 125).unwrap(),
+                Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1f0), 124).unwrap(),
+                Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1e0), 123).unwrap(),
+                Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1c0), 122).unwrap(),
+                Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x180), 121).unwrap(),
+                Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x100), 120).unwrap(),
+            ]
+        );
+        assert_eq!(
+            Ipv6Network::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0), 0).unwrap(),
+            supernets[125]
+        );
+    }
+
     #[test]
     fn supernet_none() {
         let ipv6_network = Ipv6Network::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0), 0).unwrap();
@@ -793,6 +1695,121 @@ mod tests {
             .parse::<Ipv6Network>()
             .unwrap()
             .is_global());
+        assert!(!"100::/64".parse::<Ipv6Network>().unwrap().is_global());
+    }
+
+    #[test]
+    fn halves() {
+        let ip_network = Ipv6Network::from_str("2001:db8::/64").unwrap();
+        assert_eq!(
+            Some((
+                Ipv6Network::from_str("2001:db8::/65").unwrap(),
+                Ipv6Network::from_str("2001:db8:0:0:8000::/65").unwrap(),
+            )),
+            ip_network.halves()
+        );
+        assert_eq!(
+            Some((
+                Ipv6Network::from_str("::/1").unwrap(),
+                Ipv6Network::from_str("8000::/1").unwrap(),
+            )),
+            Ipv6Network::DEFAULT_ROUTE.halves()
+        );
+        assert_eq!(
+            None,
+            Ipv6Network::from_str("2001:db8::1/128").unwrap().halves()
+        );
+    }
+
+    #[test]
+    fn is_default() {
+        assert!(Ipv6Network::DEFAULT_ROUTE.is_default());
+        assert!(!Ipv6Network::from_str("::/1").unwrap().is_default());
+        assert!(!Ipv6Network::from_str("::/128").unwrap().is_default());
+
+        const IS_DEFAULT: bool = Ipv6Network::DEFAULT_ROUTE.is_default();
+        const FIRST_ADDRESS: Ipv6Addr = Ipv6Network::DEFAULT_ROUTE.first_address();
+        const _: () = assert!(IS_DEFAULT);
+        assert_eq!(Ipv6Addr::UNSPECIFIED, FIRST_ADDRESS);
+    }
+
+    #[test]
+    fn sibling() {
+        let ip_network = Ipv6Network::from_str("2001:db8::/65").unwrap();
+        let sibling = Ipv6Network::from_str("2001:db8:0:0:8000::/65").unwrap();
+        assert_eq!(Some(sibling), ip_network.sibling());
+        assert_eq!(Some(ip_network), sibling.sibling());
+        assert_eq!(ip_network.supernet(), sibling.supernet());
+
+        assert_eq!(
+            Some(Ipv6Network::from_str("2001:db8::/128").unwrap()),
+            Ipv6Network::from_str("2001:db8::1/128").unwrap().sibling()
+        );
+        assert_eq!(
+            Some(Ipv6Network::from_str("::/1").unwrap()),
+            Ipv6Network::from_str("8000::/1").unwrap().sibling()
+        );
+        assert_eq!(None, Ipv6Network::DEFAULT_ROUTE.sibling());
+    }
+
+    #[test]
+    fn first_and_last_address() {
+        let ip_network = Ipv6Network::from_str("2001:db8::/120").unwrap();
+        assert_eq!(ip_network.network_address(), ip_network.first_address());
+        assert_eq!(
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0xff),
+            ip_network.last_address()
+        );
+    }
+
+    #[test]
+    fn contains_str() {
+        let ip_network = Ipv6Network::from_str("2001:db8::/32").unwrap();
+        assert_eq!(Ok(true), ip_network.contains_str("2001:db8:1::1"));
+        assert_eq!(Ok(false), ip_network.contains_str("2001:db9::1"));
+        assert_eq!(
+            "2001:db8::g".parse::<Ipv6Addr>().unwrap_err(),
+            ip_network.contains_str("2001:db8::g").unwrap_err()
+        );
+        assert!(ip_network.contains_str("192.168.1.1").is_err());
+    }
+
+    #[test]
+    fn registry_name() {
+        let registry_name = |network: &str| network.parse::<Ipv6Network>().unwrap().registry_name();
+
+        assert_eq!(Some("Unspecified Address"), registry_name("::/128"));
+        assert_eq!(Some("Loopback Address"), registry_name("::1/128"));
+        assert_eq!(
+            Some("IPv4-mapped Address"),
+            registry_name("::ffff:192.168.0.0/112")
+        );
+        assert_eq!(
+            Some("Discard-Only Address Block"),
+            registry_name("100::/64")
+        );
+        assert_eq!(Some("Documentation"), registry_name("2001:db8:1::/48"));
+        assert_eq!(Some("Unique-Local"), registry_name("fd00::/8"));
+        assert_eq!(Some("Link-Local Unicast"), registry_name("fe80::/64"));
+        assert_eq!(Some("Site-Local (deprecated)"), registry_name("fec0::/10"));
+
+        assert_eq!(None, registry_name("::/0"));
+        assert_eq!(None, registry_name("2001:4860::/32"));
+        assert_eq!(None, registry_name("fc00::/6"));
+    }
+
+    #[test]
+    fn is_discard_only() {
+        assert!("100::/64".parse::<Ipv6Network>().unwrap().is_discard_only());
+        assert!("100::ff00/120"
+            .parse::<Ipv6Network>()
+            .unwrap()
+            .is_discard_only());
+        assert!(!"100:0:0:1::/64"
+            .parse::<Ipv6Network>()
+            .unwrap()
+            .is_discard_only());
+        assert!(!"100::/8".parse::<Ipv6Network>().unwrap().is_discard_only());
     }
 
     #[test]
@@ -860,6 +1877,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn solicited_node_multicast() {
+        // Example from RFC 4291, section 2.7.1
+        let ip = "4037::01:800:200e:8c6c".parse::<Ipv6Addr>().unwrap();
+        assert_eq!(
+            "ff02::1:ff0e:8c6c/128".parse::<Ipv6Network>().unwrap(),
+            Ipv6Network::solicited_node_multicast(ip)
+        );
+    }
+
+    #[test]
+    fn multicast_flags() {
+        let multicast_flags =
+            |network: &str| network.parse::<Ipv6Network>().unwrap().multicast_flags();
+
+        // Netmask doesn't cover the whole flags field
+        assert_eq!(None, multicast_flags("ff00::/8"));
+        assert_eq!(None, multicast_flags("ff00::/11"));
+        assert_eq!(None, multicast_flags("2001:db8::/32"));
+        assert_eq!(
+            Some(Ipv6MulticastFlags {
+                transient: true,
+                prefix_based: false,
+                rendezvous_point: false,
+            }),
+            multicast_flags("ff10::/12")
+        );
+        assert_eq!(
+            Some(Ipv6MulticastFlags {
+                transient: false,
+                prefix_based: false,
+                rendezvous_point: false,
+            }),
+            multicast_flags("ff02::1/128")
+        );
+        assert_eq!(
+            Some(Ipv6MulticastFlags {
+                transient: true,
+                prefix_based: false,
+                rendezvous_point: false,
+            }),
+            multicast_flags("ff15::/16")
+        );
+        assert_eq!(
+            Some(Ipv6MulticastFlags {
+                transient: true,
+                prefix_based: true,
+                rendezvous_point: true,
+            }),
+            multicast_flags("ff7e::/16")
+        );
+    }
+
     #[test]
     fn collapse_addresses() {
         let addresses = [
@@ -872,18 +1942,110 @@ mod tests {
         assert_eq!(Ipv6Network::from_str("2001::/96").unwrap(), collapsed[0]);
     }
 
+    #[test]
+    fn collapse_addresses_deterministic() {
+        let mut addresses = vec![
+            Ipv6Network::from_str("2001:db8::/33").unwrap(),
+            Ipv6Network::from_str("2001:db8:8000::/33").unwrap(),
+            Ipv6Network::from_str("2001:db8:1::/48").unwrap(),
+            Ipv6Network::from_str("2001::/120").unwrap(),
+            Ipv6Network::from_str("::1/128").unwrap(),
+        ];
+        let expected = vec![
+            Ipv6Network::from_str("::1/128").unwrap(),
+            Ipv6Network::from_str("2001::/120").unwrap(),
+            Ipv6Network::from_str("2001:db8::/32").unwrap(),
+        ];
+
+        for _ in 0..addresses.len() {
+            addresses.rotate_left(1);
+            assert_eq!(expected, Ipv6Network::collapse_addresses(&addresses));
+            addresses.reverse();
+            assert_eq!(expected, Ipv6Network::collapse_addresses(&addresses));
+        }
+    }
+
     #[test]
     fn parse() {
         let ip_network: Ipv6Network = "2001:db8::/32".parse().unwrap();
         assert_eq!(ip_network, return_test_ipv6_network());
     }
 
+    #[test]
+    fn parse_invalid_netmask_span() {
+        let ip_network = Ipv6Network::from_str_truncate("2001:db8::1/1000");
+        assert_eq!(
+            IpNetworkParseError::InvalidNetmaskFormat { span: 12..16 },
+            ip_network.unwrap_err()
+        );
+    }
+
     #[test]
     fn format() {
         let ip_network = return_test_ipv6_network();
         assert_eq!(ip_network.to_string(), "2001:db8::/32");
     }
 
+    #[test]
+    fn parse_without_netmask() {
+        let ip = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        assert_eq!(
+            Ipv6Network::from(ip),
+            "2001:db8::1".parse::<Ipv6Network>().unwrap()
+        );
+        assert_eq!(
+            IpNetworkParseError::InvalidFormatError,
+            "2001:db8::1/".parse::<Ipv6Network>().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn stable_hash() {
+        let ip_network = return_test_ipv6_network();
+        assert_eq!(0x99c8_a50b_3c0c_cf85, ip_network.stable_hash());
+    }
+
+    #[test]
+    fn from_str_truncate_without_netmask() {
+        let ip = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        assert_eq!(
+            Ipv6Network::from(ip),
+            Ipv6Network::from_str_truncate("2001:db8::1").unwrap()
+        );
+    }
+
+    #[test]
+    fn write_cidr_fixed_buffer() {
+        let mut buffer = FixedBuffer::<43>::new();
+        let ip = Ipv6Addr::new(
+            0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xfffe,
+        );
+        let ip_network = Ipv6Network::new(ip, 127).unwrap();
+        ip_network.write_cidr(&mut buffer).unwrap();
+        assert_eq!(
+            buffer.as_str(),
+            "ffff:ffff:ffff:ffff:ffff:ffff:ffff:fffe/127"
+        );
+
+        // Buffer doesn't have enough capacity for second network
+        assert!(ip_network.write_cidr(&mut buffer).is_err());
+    }
+
+    #[test]
+    fn format_padding() {
+        let ip_network = return_test_ipv6_network();
+        assert_eq!(format!("{:>18}", ip_network), "     2001:db8::/32");
+    }
+
+    #[test]
+    fn format_uppercase() {
+        let ip_network = return_test_ipv6_network();
+        assert_eq!("2001:DB8::/32", ip_network.to_string_uppercase());
+
+        let ip_network = Ipv6Network::from_str("::ffff:10.0.0.1/128").unwrap();
+        assert_eq!("::FFFF:10.0.0.1/128", ip_network.to_string_uppercase());
+    }
+
     #[test]
     fn from_ipv6addr() {
         let ip = Ipv6Addr::new(0x2001, 0x0db8, 0xc000, 0, 0, 0, 0, 0);
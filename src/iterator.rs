@@ -1,5 +1,5 @@
 use std::net::{Ipv4Addr, Ipv6Addr};
-use crate::{Ipv4Network, Ipv6Network};
+use crate::{IpNetworkError, Ipv4Network, Ipv6Network};
 use crate::helpers;
 
 #[cfg(target_pointer_width = "16")]
@@ -8,8 +8,6 @@ const POINTER_WIDTH: u32 = 16;
 const POINTER_WIDTH: u32 = 32;
 #[cfg(target_pointer_width = "64")]
 const POINTER_WIDTH: u32 = 64;
-#[cfg(target_pointer_width = "128")]
-const POINTER_WIDTH: u32 = 128;
 
 /// IPv4 range iterator.
 pub struct Ipv4RangeIterator {
@@ -23,7 +21,9 @@ impl Ipv4RangeIterator {
     ///
     /// # Panics
     ///
-    /// When `to` address is bigger or same than `from` address.
+    /// When `to` address is smaller than `from` address. Use [`try_new`] for non panicking version.
+    ///
+    /// [`try_new`]: #method.try_new
     ///
     /// # Examples
     ///
@@ -40,26 +40,33 @@ impl Ipv4RangeIterator {
     /// assert_eq!(iterator.last().unwrap(), Ipv4Addr::new(192, 168, 2, 255));
     /// ```
     pub fn new(from: Ipv4Addr, to: Ipv4Addr) -> Self {
+        Self::try_new(from, to).expect("invalid range")
+    }
+
+    /// Constructs new `Ipv4RangeIterator` same as [`new`], but returns `IpNetworkError::InvalidRange`
+    /// instead of panic when `to` address is smaller than `from` address.
+    ///
+    /// [`new`]: #method.new
+    pub fn try_new(from: Ipv4Addr, to: Ipv4Addr) -> Result<Self, IpNetworkError> {
         let current = u32::from(from);
         let to = u32::from(to);
-        assert!(to >= current);
-        Self {
+        if to < current {
+            return Err(IpNetworkError::InvalidRange);
+        }
+
+        Ok(Self {
             current,
             to,
             is_done: false,
-        }
+        })
     }
 
     /// Constructs new `Ipv4RangeIterator` that iterates host (without network and broadcast address)
-    /// IPs in Ipv4Network.
+    /// IPs in Ipv4Network. For networks with netmask 31 (point-to-point links, RFC 3021) and 32,
+    /// all addresses in network are hosts.
     pub fn hosts(network: Ipv4Network) -> Self {
         if network.netmask() >= 31 {
-            // Network doesn't contains any host IPs, create empty iterator.
-            Self {
-                current: 0,
-                to: 0,
-                is_done: true,
-            }
+            Self::new(network.network_address(), network.broadcast_address())
         } else {
             let from = Ipv4Addr::from(u32::from(network.network_address()) + 1);
             let to = Ipv4Addr::from(u32::from(network.broadcast_address()) - 1);
@@ -87,7 +94,7 @@ impl Iterator for Ipv4RangeIterator {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if self.is_done {
+        if self.is_done || self.current > self.to {
             return (0, Some(0));
         }
 
@@ -114,35 +121,104 @@ impl Ipv4NetworkIterator {
     /// # Panics
     ///
     /// When `new_netmask` is smaller than `network` netmask or when `net_netmask` is bigger than 32.
+    /// Use [`try_new`] for non panicking version.
+    ///
+    /// [`try_new`]: #method.try_new
     pub fn new(network: Ipv4Network, new_netmask: u8) -> Self {
-        assert!(new_netmask <= Ipv4Network::LENGTH);
+        Self::try_new(network, new_netmask).expect("invalid new netmask")
+    }
+
+    /// Constructs new `Ipv4NetworkIterator` same as [`new`], but returns `IpNetworkError::NetmaskError`
+    /// instead of panic when `new_netmask` is smaller than `network` netmask or when `new_netmask`
+    /// is bigger than 32.
+    ///
+    /// [`new`]: #method.new
+    pub fn try_new(network: Ipv4Network, new_netmask: u8) -> Result<Self, IpNetworkError> {
+        if new_netmask > Ipv4Network::LENGTH {
+            return Err(IpNetworkError::NetmaskError(new_netmask));
+        }
 
         if network.netmask() == Ipv4Network::LENGTH || network.netmask() == new_netmask {
-            return Self {
+            return Ok(Self {
                 current: 0,
                 to: 0,
                 new_netmask: 0,
                 is_done: true,
-            };
+            });
         }
 
-        assert!(network.netmask() < new_netmask);
+        if network.netmask() > new_netmask {
+            return Err(IpNetworkError::NetmaskError(new_netmask));
+        }
 
         let current = u32::from(network.network_address());
         let mask =
             !helpers::bite_mask(32 - (new_netmask - network.netmask())) << (32 - new_netmask);
         let to = current | mask;
 
-        Self {
+        Ok(Self {
             current,
             to,
             new_netmask,
             is_done: false,
+        })
+    }
+
+    /// Constructs new `Ipv4NetworkIterator`, that iterates over all networks with `prefix` netmask,
+    /// which network address is between `start` and `end` address (both inclusive). `start` is
+    /// aligned up and `end` is aligned down to `prefix` boundary.
+    ///
+    /// # Panics
+    ///
+    /// When `prefix` is bigger than 32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    /// use ip_network::iterator::Ipv4NetworkIterator;
+    ///
+    /// let mut iterator = Ipv4NetworkIterator::between(
+    ///     Ipv4Addr::new(192, 168, 1, 10),
+    ///     Ipv4Addr::new(192, 168, 1, 200),
+    ///     26,
+    /// );
+    /// assert_eq!(iterator.next().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 64), 26)?);
+    /// assert_eq!(iterator.last().unwrap(), Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 192), 26)?);
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn between(start: Ipv4Addr, end: Ipv4Addr, prefix: u8) -> Self {
+        assert!(prefix <= Ipv4Network::LENGTH);
+
+        let mask = helpers::bite_mask(prefix);
+        let start = u32::from(start);
+        let current = if start & !mask == 0 {
+            Some(start)
+        } else {
+            1u32.checked_shl(u32::from(Ipv4Network::LENGTH - prefix))
+                .and_then(|step| (start & mask).checked_add(step))
+        };
+        let to = u32::from(end) & mask;
+
+        match current {
+            Some(current) if current <= to => Self {
+                current,
+                to,
+                new_netmask: prefix,
+                is_done: false,
+            },
+            _ => Self {
+                current: 0,
+                to: 0,
+                new_netmask: 0,
+                is_done: true,
+            },
         }
     }
 
-    fn step(&self) -> u32 {
-        1 << (32 - self.new_netmask)
+    fn step(&self) -> Option<u32> {
+        1u32.checked_shl(u32::from(Ipv4Network::LENGTH - self.new_netmask))
     }
 }
 
@@ -153,7 +229,7 @@ impl Iterator for Ipv4NetworkIterator {
         if self.current <= self.to && !self.is_done {
             let output = self.current;
 
-            match self.current.checked_add(self.step()) {
+            match self.step().and_then(|step| self.current.checked_add(step)) {
                 Some(x) => self.current = x,
                 None => self.is_done = true,
             };
@@ -168,13 +244,67 @@ impl Iterator for Ipv4NetworkIterator {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if self.is_done {
+        if self.is_done || self.current > self.to {
             return (0, Some(0));
         }
 
-        let remaining = ((self.to - self.current) / self.step() + 1) as usize;
+        let remaining = match self.step() {
+            Some(step) => ((self.to - self.current) / step + 1) as usize,
+            None => 1, // only network with netmask 0
+        };
         (remaining, Some(remaining))
     }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.is_done || self.current > self.to {
+            return None;
+        }
+
+        let skip = match self.step() {
+            Some(step) => u64::from(step).checked_mul(n as u64),
+            None if n == 0 => Some(0),
+            None => None, // only network with netmask 0
+        };
+
+        match skip.map(|skip| u64::from(self.current) + skip) {
+            Some(current) if current <= u64::from(self.to) => {
+                self.current = current as u32;
+                self.next()
+            }
+            _ => {
+                self.is_done = true;
+                None
+            }
+        }
+    }
+}
+
+impl DoubleEndedIterator for Ipv4NetworkIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current <= self.to && !self.is_done {
+            let output = self.to;
+
+            if self.to == self.current {
+                self.is_done = true;
+            } else {
+                match self.step().and_then(|step| self.to.checked_sub(step)) {
+                    Some(x) => self.to = x,
+                    None => self.is_done = true,
+                };
+            }
+
+            Some(Self::Item {
+                network_address: Ipv4Addr::from(output),
+                netmask: self.new_netmask,
+            })
+        } else {
+            None
+        }
+    }
 }
 
 impl ExactSizeIterator for Ipv4NetworkIterator {}
@@ -195,31 +325,47 @@ impl Ipv6NetworkIterator {
     /// # Panics
     ///
     /// When `new_netmask` is smaller than `network` netmask or when `net_netmask` is bigger than 128.
+    /// Use [`try_new`] for non panicking version.
+    ///
+    /// [`try_new`]: #method.try_new
     pub fn new(network: Ipv6Network, new_netmask: u8) -> Self {
-        assert!(new_netmask <= Ipv6Network::LENGTH);
+        Self::try_new(network, new_netmask).expect("invalid new netmask")
+    }
+
+    /// Constructs new `Ipv6NetworkIterator` same as [`new`], but returns `IpNetworkError::NetmaskError`
+    /// instead of panic when `new_netmask` is smaller than `network` netmask or when `new_netmask`
+    /// is bigger than 128.
+    ///
+    /// [`new`]: #method.new
+    pub fn try_new(network: Ipv6Network, new_netmask: u8) -> Result<Self, IpNetworkError> {
+        if new_netmask > Ipv6Network::LENGTH {
+            return Err(IpNetworkError::NetmaskError(new_netmask));
+        }
 
         if network.netmask() == Ipv6Network::LENGTH || network.netmask() == new_netmask {
-            return Self {
+            return Ok(Self {
                 current: 0,
                 to: 0,
                 new_netmask: 0,
                 is_done: true,
-            };
+            });
         }
 
-        assert!(network.netmask() < new_netmask);
+        if network.netmask() > new_netmask {
+            return Err(IpNetworkError::NetmaskError(new_netmask));
+        }
 
         let current = u128::from(network.network_address());
         let mask = !helpers::bite_mask_u128(128 - (new_netmask - network.netmask()))
             << (128 - new_netmask);
         let to = current | mask;
 
-        Self {
+        Ok(Self {
             current,
             to,
             new_netmask,
             is_done: false,
-        }
+        })
     }
 
     fn step(&self) -> u128 {
@@ -273,7 +419,7 @@ impl ExactSizeIterator for Ipv6NetworkIterator {}
 #[cfg(test)]
 mod tests {
     use std::net::{Ipv4Addr, Ipv6Addr};
-    use crate::{Ipv4Network, Ipv6Network};
+    use crate::{IpNetworkError, Ipv4Network, Ipv6Network};
     use super::{Ipv4NetworkIterator, Ipv4RangeIterator, Ipv6NetworkIterator};
 
     #[test]
@@ -312,6 +458,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::double_ended_iterator_last)]
     fn ipv4_network_iterator() {
         let network = Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 0), 8).unwrap();
         let mut iterator = Ipv4NetworkIterator::new(network, 16);
@@ -342,6 +489,249 @@ mod tests {
         assert_eq!(0, iterator.len());
     }
 
+    #[test]
+    fn ipv4_range_iterator_try_new() {
+        let from = Ipv4Addr::new(192, 168, 2, 0);
+        let to = Ipv4Addr::new(192, 168, 2, 255);
+        assert_eq!(256, Ipv4RangeIterator::try_new(from, to).unwrap().len());
+        assert_eq!(1, Ipv4RangeIterator::try_new(from, from).unwrap().len());
+    }
+
+    #[test]
+    fn ipv4_range_iterator_try_new_reversed() {
+        let from = Ipv4Addr::new(192, 168, 2, 255);
+        let to = Ipv4Addr::new(192, 168, 2, 0);
+        assert_eq!(
+            IpNetworkError::InvalidRange,
+            Ipv4RangeIterator::try_new(from, to).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv4_network_iterator_try_new() {
+        let network = Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 0), 8).unwrap();
+        assert_eq!(
+            256,
+            Ipv4NetworkIterator::try_new(network, 16).unwrap().len()
+        );
+        assert_eq!(0, Ipv4NetworkIterator::try_new(network, 8).unwrap().len());
+    }
+
+    #[test]
+    fn ipv4_network_iterator_try_new_netmask_too_big() {
+        let network = Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 0), 8).unwrap();
+        assert_eq!(
+            IpNetworkError::NetmaskError(33),
+            Ipv4NetworkIterator::try_new(network, 33).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv4_network_iterator_try_new_netmask_smaller() {
+        let network = Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 0), 8).unwrap();
+        assert_eq!(
+            IpNetworkError::NetmaskError(7),
+            Ipv4NetworkIterator::try_new(network, 7).err().unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))] // currently, miri doesnt support should_panic tests
+    fn ipv4_network_iterator_new_netmask_smaller() {
+        let network = Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 0), 8).unwrap();
+        Ipv4NetworkIterator::new(network, 7);
+    }
+
+    #[test]
+    fn ipv4_network_iterator_len_after_end() {
+        let network = Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 0), 8).unwrap();
+        let mut iterator = Ipv4NetworkIterator::new(network, 9);
+        iterator.next().unwrap();
+        iterator.next().unwrap();
+        assert!(iterator.next().is_none());
+        assert_eq!(0, iterator.len());
+    }
+
+    #[test]
+    fn ipv4_network_iterator_between() {
+        let iterator = Ipv4NetworkIterator::between(
+            Ipv4Addr::new(10, 0, 0, 1),
+            Ipv4Addr::new(10, 0, 1, 64),
+            26,
+        );
+        assert_eq!(iterator.len(), 5);
+        assert_eq!(
+            iterator.collect::<Vec<_>>(),
+            vec![
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 64), 26).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 128), 26).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 192), 26).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 1, 0), 26).unwrap(),
+                Ipv4Network::new(Ipv4Addr::new(10, 0, 1, 64), 26).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn ipv4_network_iterator_between_empty() {
+        let iterator = Ipv4NetworkIterator::between(
+            Ipv4Addr::new(10, 0, 0, 1),
+            Ipv4Addr::new(10, 0, 0, 63),
+            26,
+        );
+        assert_eq!(0, iterator.len());
+
+        let iterator = Ipv4NetworkIterator::between(
+            Ipv4Addr::new(255, 255, 255, 1),
+            Ipv4Addr::new(255, 255, 255, 255),
+            24,
+        );
+        assert_eq!(0, iterator.len());
+    }
+
+    #[test]
+    fn ipv4_network_iterator_between_whole_range() {
+        let mut iterator = Ipv4NetworkIterator::between(
+            Ipv4Addr::new(0, 0, 0, 0),
+            Ipv4Addr::new(255, 255, 255, 255),
+            0,
+        );
+        assert_eq!(1, iterator.len());
+        assert_eq!(Some(Ipv4Network::DEFAULT_ROUTE), iterator.next());
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn ipv4_network_iterator_between_unaligned_prefix_0() {
+        let mut iterator = Ipv4NetworkIterator::between(
+            Ipv4Addr::new(1, 0, 0, 0),
+            Ipv4Addr::new(255, 255, 255, 255),
+            0,
+        );
+        assert_eq!(0, iterator.len());
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn ipv4_network_iterator_nth() {
+        let network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 16).unwrap();
+        let mut iterator = Ipv4NetworkIterator::new(network, 24);
+        assert_eq!(
+            Some(Ipv4Network::new(Ipv4Addr::new(10, 0, 255, 0), 24).unwrap()),
+            iterator.nth(255)
+        );
+        assert!(iterator.next().is_none());
+
+        let mut iterator = Ipv4NetworkIterator::new(network, 24);
+        assert_eq!(
+            Some(Ipv4Network::new(Ipv4Addr::new(10, 0, 10, 0), 24).unwrap()),
+            iterator.nth(10)
+        );
+        assert_eq!(
+            Some(Ipv4Network::new(Ipv4Addr::new(10, 0, 11, 0), 24).unwrap()),
+            iterator.next()
+        );
+        assert_eq!(244, iterator.len());
+    }
+
+    #[test]
+    fn ipv4_network_iterator_nth_out_of_range() {
+        let network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 16).unwrap();
+        let mut iterator = Ipv4NetworkIterator::new(network, 24);
+        assert!(iterator.nth(256).is_none());
+        assert!(iterator.next().is_none());
+        assert_eq!(0, iterator.len());
+
+        let mut iterator = Ipv4NetworkIterator::new(Ipv4Network::DEFAULT_ROUTE, 32);
+        assert!(iterator.nth(usize::MAX).is_none());
+
+        let mut iterator = Ipv4NetworkIterator::new(Ipv4Network::DEFAULT_ROUTE, 32);
+        assert_eq!(
+            Some(Ipv4Network::new(Ipv4Addr::new(255, 255, 255, 255), 32).unwrap()),
+            iterator.nth(u32::MAX as usize)
+        );
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    #[allow(clippy::iter_nth_zero)]
+    fn ipv4_network_iterator_nth_whole_range() {
+        let mut iterator = Ipv4NetworkIterator::between(
+            Ipv4Addr::new(0, 0, 0, 0),
+            Ipv4Addr::new(255, 255, 255, 255),
+            0,
+        );
+        assert!(iterator.nth(1).is_none());
+
+        let mut iterator = Ipv4NetworkIterator::between(
+            Ipv4Addr::new(0, 0, 0, 0),
+            Ipv4Addr::new(255, 255, 255, 255),
+            0,
+        );
+        assert_eq!(Some(Ipv4Network::DEFAULT_ROUTE), iterator.nth(0));
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn ipv4_network_iterator_rev() {
+        let network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        for prefix in &[8, 9, 10, 16, 20] {
+            let mut forward = Ipv4NetworkIterator::new(network, *prefix).collect::<Vec<_>>();
+            forward.reverse();
+            let backward = Ipv4NetworkIterator::new(network, *prefix)
+                .rev()
+                .collect::<Vec<_>>();
+            assert_eq!(forward, backward);
+        }
+
+        let mut forward =
+            Ipv4NetworkIterator::new(Ipv4Network::DEFAULT_ROUTE, 4).collect::<Vec<_>>();
+        forward.reverse();
+        let backward = Ipv4NetworkIterator::new(Ipv4Network::DEFAULT_ROUTE, 4)
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn ipv4_network_iterator_both_ends() {
+        let network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+        let mut iterator = Ipv4NetworkIterator::new(network, 26);
+        assert_eq!(
+            iterator.next().unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 26).unwrap()
+        );
+        assert_eq!(
+            iterator.next_back().unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 192), 26).unwrap()
+        );
+        assert_eq!(2, iterator.len());
+        assert_eq!(
+            iterator.next_back().unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 128), 26).unwrap()
+        );
+        assert_eq!(
+            iterator.next().unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 64), 26).unwrap()
+        );
+        assert_eq!(0, iterator.len());
+        assert!(iterator.next().is_none());
+        assert!(iterator.next_back().is_none());
+    }
+
+    #[test]
+    fn ipv4_network_iterator_rev_whole_range() {
+        let mut iterator = Ipv4NetworkIterator::between(
+            Ipv4Addr::new(0, 0, 0, 0),
+            Ipv4Addr::new(255, 255, 255, 255),
+            0,
+        );
+        assert_eq!(Some(Ipv4Network::DEFAULT_ROUTE), iterator.next_back());
+        assert!(iterator.next_back().is_none());
+        assert!(iterator.next().is_none());
+    }
+
     #[test]
     fn ipv6_network_iterator() {
         let ip = Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0);
@@ -357,6 +747,24 @@ mod tests {
         assert!(iterator.next().is_none());
     }
 
+    #[test]
+    fn ipv6_network_iterator_try_new_netmask_too_big() {
+        let network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 16).unwrap();
+        assert_eq!(
+            IpNetworkError::NetmaskError(129),
+            Ipv6NetworkIterator::try_new(network, 129).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_network_iterator_try_new_netmask_smaller() {
+        let network = Ipv6Network::new(Ipv6Addr::new(0x2001, 0, 0, 0, 0, 0, 0, 0), 16).unwrap();
+        assert_eq!(
+            IpNetworkError::NetmaskError(15),
+            Ipv6NetworkIterator::try_new(network, 15).err().unwrap()
+        );
+    }
+
     #[test]
     #[should_panic] // because range is bigger than `usize` on 64bit machine
     #[cfg(not(miri))] // currently, miri doesnt support should_panic tests
@@ -7,6 +7,7 @@
 //! * `serde` – for serialization and deserialization by [Serde framework](https://serde.rs).
 //! * `diesel` – support for [PostgreSQL CIDR type] with [Diesel ORM](https://diesel.rs).
 //! * `postgres` – support for [PostgreSQL CIDR type] with [postgres crate](https://github.com/sfackler/rust-postgres).
+//! * `rand` – random subnets generation with [rand crate](https://github.com/rust-random/rand).
 //!
 //! ## Examples
 //!
@@ -28,59 +29,111 @@
 #[macro_use]
 extern crate diesel;
 
+mod aggregate;
+mod contained;
 #[cfg(feature = "diesel")]
 /// Support for Diesel PostgreSQL CIDR type.
 pub mod diesel_support;
+mod display_host;
 mod helpers;
 mod ip_network;
 mod ipv4_network;
 mod ipv6_network;
 /// `Ipv4RangeIterator`, `Ipv4NetworkIterator`, and `Ipv6NetworkIterator`.
 pub mod iterator;
-#[cfg(any(feature = "diesel", feature = "postgres"))]
 mod postgres_common;
 #[cfg(feature = "postgres")]
 mod postgres_support;
+#[cfg(feature = "rand")]
+mod rand_support;
 #[cfg(feature = "serde")]
-mod serde_support;
+/// Support for Serde serialization and deserialization.
+pub mod serde_support;
+
+#[cfg(feature = "serde")]
+/// Modules for use with Serde `#[serde(with = "...")]` field attribute.
+pub mod serde_ext {
+    pub use crate::serde_support::compact;
+}
 
 use std::error::Error;
 use std::fmt;
+use std::net::Ipv4Addr;
+use std::ops::Range;
 
+pub use self::aggregate::AggregateExt;
+pub use self::contained::Contained;
+pub use self::display_host::DisplayHost;
+pub use self::helpers::{same_prefix, same_prefix_ipv6};
 pub use self::ip_network::IpNetwork;
-pub use self::ipv4_network::Ipv4Network;
-pub use self::ipv6_network::{Ipv6MulticastScope, Ipv6Network};
+pub use self::ipv4_network::{Ipv4Network, Netmask};
+pub use self::ipv6_network::{Ipv6MulticastFlags, Ipv6MulticastScope, Ipv6Network};
 
 /// Errors when creating new IPv4 or IPv6 networks.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum IpNetworkError {
     /// Network mask is bigger than possible for given IP version (32 for IPv4, 128 for IPv6).
     NetmaskError(u8),
     /// Host bits are set in given network IP address.
     HostBitsSet,
+    /// Given bytes are not valid binary representation of IPv4 or IPv6 network.
+    InvalidBytes(&'static str),
+    /// Given bytes contain IP address with bad length. `expected` is length in bytes for given IP
+    /// version, `actual` is length of provided address.
+    InvalidAddressLength { expected: u8, actual: usize },
+    /// End address of range is smaller than start address.
+    InvalidRange,
+    /// Subnet for given number of hosts doesn't fit into network.
+    NotEnoughAddresses(u32),
+    /// Network mask in dotted form doesn't have contiguous bits set (like `255.255.0.255`).
+    NonContiguousNetmask(Ipv4Addr),
 }
 
 impl Error for IpNetworkError {}
 
 impl fmt::Display for IpNetworkError {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let description = match *self {
-            IpNetworkError::NetmaskError(_) => "invalid netmask",
-            IpNetworkError::HostBitsSet => "IP network address has host bits set",
-        };
-        write!(fmt, "{}", description)
+        match *self {
+            IpNetworkError::NetmaskError(_) => write!(fmt, "invalid netmask"),
+            IpNetworkError::HostBitsSet => write!(fmt, "IP network address has host bits set"),
+            IpNetworkError::InvalidBytes(description) => write!(fmt, "{}", description),
+            IpNetworkError::InvalidAddressLength { expected, actual } => write!(
+                fmt,
+                "IP address has bad length '{}', expected {} bytes",
+                actual, expected
+            ),
+            IpNetworkError::InvalidRange => {
+                write!(fmt, "range end address is smaller than start address")
+            }
+            IpNetworkError::NotEnoughAddresses(host_count) => {
+                write!(
+                    fmt,
+                    "subnet for {} hosts doesn't fit into network",
+                    host_count
+                )
+            }
+            IpNetworkError::NonContiguousNetmask(netmask) => {
+                write!(fmt, "netmask {} is not contiguous", netmask)
+            }
+        }
     }
 }
 
 /// Errors from IPv4 or IPv6 network parsing.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum IpNetworkParseError {
-    /// Network mask is not valid integer between 0 and 255.
-    InvalidNetmaskFormat,
+    /// Network mask is not valid integer between 0 and 255. `span` is byte range of the netmask
+    /// in parsed string.
+    InvalidNetmaskFormat { span: Range<usize> },
     /// Network address has invalid format (not X/Y).
     InvalidFormatError,
     /// Invalid IP address syntax (IPv4 or IPv6).
     AddrParseError,
+    /// IPv4 address has correct format, but one of its octets is bigger than 255. `span` is byte
+    /// range of the octet in parsed string.
+    AddrOctetOutOfRange { span: Range<usize> },
     /// Error when creating new IPv4 or IPv6 networks.
     IpNetworkError(IpNetworkError),
 }
@@ -97,9 +150,18 @@ impl Error for IpNetworkParseError {
 impl fmt::Display for IpNetworkParseError {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            IpNetworkParseError::InvalidNetmaskFormat => write!(fmt, "invalid netmask format"),
+            IpNetworkParseError::InvalidNetmaskFormat { ref span } => write!(
+                fmt,
+                "invalid netmask format at {}..{}",
+                span.start, span.end
+            ),
             IpNetworkParseError::InvalidFormatError => write!(fmt, "invalid format"),
             IpNetworkParseError::AddrParseError => write!(fmt, "invalid IP address syntax"),
+            IpNetworkParseError::AddrOctetOutOfRange { ref span } => write!(
+                fmt,
+                "IPv4 address octet out of range at {}..{}",
+                span.start, span.end
+            ),
             IpNetworkParseError::IpNetworkError(ref ip_network_error) => {
                 write!(fmt, "{}", ip_network_error)
             }
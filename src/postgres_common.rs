@@ -1,46 +1,74 @@
-use std::error::Error;
 use std::net::{Ipv4Addr, Ipv6Addr};
-use crate::{Ipv4Network, Ipv6Network};
+use crate::{IpNetwork, IpNetworkError, Ipv4Network, Ipv6Network};
 
 // TODO: These constants are true for Linux, but we have to check it for Windows and other systems
 pub const IPV4_TYPE: u8 = 2;
 pub const IPV6_TYPE: u8 = 3;
 
+/// Family, netmask, CIDR flag and address length bytes.
+const HEADER_LENGTH: usize = 4;
+
 #[inline]
-pub fn from_sql_ipv4_network(raw: &[u8]) -> Result<Ipv4Network, Box<dyn Error + Sync + Send>> {
-    assert!(raw.len() >= 7);
+pub fn from_sql_ipv4_network(raw: &[u8]) -> Result<Ipv4Network, IpNetworkError> {
+    if raw.len() < HEADER_LENGTH {
+        return Err(IpNetworkError::InvalidBytes("CIDR header is too short"));
+    }
 
     if raw[0] != IPV4_TYPE {
-        return Err("CIDR is not IP version 4".into());
+        return Err(IpNetworkError::InvalidBytes("CIDR is not IP version 4"));
     }
 
     if raw[2] != 1 {
-        return Err("This field is not CIDR type, probably INET type".into());
+        return Err(IpNetworkError::InvalidBytes(
+            "This field is not CIDR type, probably INET type",
+        ));
     }
 
     if raw[3] != Ipv4Network::LENGTH / 8 {
-        return Err(format!("CIDR is IP version 4, but have bad length '{}'", raw[3]).into());
+        return Err(IpNetworkError::InvalidAddressLength {
+            expected: Ipv4Network::LENGTH / 8,
+            actual: usize::from(raw[3]),
+        });
+    }
+
+    if raw.len() != HEADER_LENGTH + usize::from(raw[3]) {
+        return Err(IpNetworkError::InvalidBytes(
+            "CIDR address length doesn't match data length",
+        ));
     }
 
     let network_address = Ipv4Addr::new(raw[4], raw[5], raw[6], raw[7]);
     let netmask = raw[1];
-    Ok(Ipv4Network::new(network_address, netmask)?)
+    Ipv4Network::new(network_address, netmask)
 }
 
 #[inline]
-pub fn from_sql_ipv6_network(raw: &[u8]) -> Result<Ipv6Network, Box<dyn Error + Sync + Send>> {
-    assert!(raw.len() >= 20);
+pub fn from_sql_ipv6_network(raw: &[u8]) -> Result<Ipv6Network, IpNetworkError> {
+    if raw.len() < HEADER_LENGTH {
+        return Err(IpNetworkError::InvalidBytes("CIDR header is too short"));
+    }
 
     if raw[0] != IPV6_TYPE {
-        return Err("CIDR is not IP version 6".into());
+        return Err(IpNetworkError::InvalidBytes("CIDR is not IP version 6"));
     }
 
     if raw[2] != 1 {
-        return Err("This field is not CIDR type, probably INET type".into());
+        return Err(IpNetworkError::InvalidBytes(
+            "This field is not CIDR type, probably INET type",
+        ));
     }
 
     if raw[3] != Ipv6Network::LENGTH / 8 {
-        return Err(format!("CIDR is IP version 6, but have bad length '{}'", raw[3]).into());
+        return Err(IpNetworkError::InvalidAddressLength {
+            expected: Ipv6Network::LENGTH / 8,
+            actual: usize::from(raw[3]),
+        });
+    }
+
+    if raw.len() != HEADER_LENGTH + usize::from(raw[3]) {
+        return Err(IpNetworkError::InvalidBytes(
+            "CIDR address length doesn't match data length",
+        ));
     }
 
     let mut octets = [0; 16];
@@ -48,7 +76,18 @@ pub fn from_sql_ipv6_network(raw: &[u8]) -> Result<Ipv6Network, Box<dyn Error +
     let network_address = Ipv6Addr::from(octets);
 
     let netmask = raw[1];
-    Ok(Ipv6Network::new(network_address, netmask)?)
+    Ipv6Network::new(network_address, netmask)
+}
+
+#[inline]
+pub fn from_sql_ip_network(raw: &[u8]) -> Result<IpNetwork, IpNetworkError> {
+    match raw.first() {
+        Some(&IPV4_TYPE) => Ok(IpNetwork::V4(from_sql_ipv4_network(raw)?)),
+        Some(&IPV6_TYPE) => Ok(IpNetwork::V6(from_sql_ipv6_network(raw)?)),
+        _ => Err(IpNetworkError::InvalidBytes(
+            "CIDR is not IP version 4 or 6",
+        )),
+    }
 }
 
 #[inline]
@@ -74,3 +113,72 @@ pub fn to_sql_ipv6_network(network: &Ipv6Network) -> [u8; 20] {
     bytes[4..].copy_from_slice(&ip_octets);
     bytes
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::IpNetworkError;
+    use super::{from_sql_ip_network, from_sql_ipv4_network, from_sql_ipv6_network};
+
+    #[test]
+    fn ipv4_empty() {
+        assert_eq!(
+            Err(IpNetworkError::InvalidBytes("CIDR header is too short")),
+            from_sql_ipv4_network(&[])
+        );
+    }
+
+    #[test]
+    fn ipv4_truncated_address() {
+        assert_eq!(
+            Err(IpNetworkError::InvalidBytes(
+                "CIDR address length doesn't match data length"
+            )),
+            from_sql_ipv4_network(&[2, 16, 1, 4, 192, 168, 0])
+        );
+    }
+
+    #[test]
+    fn ipv4_bad_address_length() {
+        assert_eq!(
+            Err(IpNetworkError::InvalidAddressLength {
+                expected: 4,
+                actual: 3
+            }),
+            from_sql_ipv4_network(&[2, 16, 1, 3, 192, 168, 0])
+        );
+        assert_eq!(
+            "IP address has bad length '3', expected 4 bytes",
+            from_sql_ipv4_network(&[2, 16, 1, 3, 192, 168, 0])
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn ipv6_truncated_header() {
+        assert_eq!(
+            Err(IpNetworkError::InvalidBytes("CIDR header is too short")),
+            from_sql_ipv6_network(&[3, 32, 1])
+        );
+    }
+
+    #[test]
+    fn ipv6_truncated_address() {
+        assert_eq!(
+            Err(IpNetworkError::InvalidBytes(
+                "CIDR address length doesn't match data length"
+            )),
+            from_sql_ipv6_network(&[3, 32, 1, 16, 0x20, 0x01, 0x0d, 0xb8])
+        );
+    }
+
+    #[test]
+    fn ip_network_empty() {
+        assert_eq!(
+            Err(IpNetworkError::InvalidBytes(
+                "CIDR is not IP version 4 or 6"
+            )),
+            from_sql_ip_network(&[])
+        );
+    }
+}
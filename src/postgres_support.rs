@@ -8,7 +8,7 @@ type PostgresResult<T> = Result<T, Box<dyn Error + Sync + Send>>;
 
 impl<'a> FromSql<'a> for Ipv4Network {
     fn from_sql(_: &Type, raw: &'a [u8]) -> PostgresResult<Ipv4Network> {
-        postgres_common::from_sql_ipv4_network(raw)
+        Ok(postgres_common::from_sql_ipv4_network(raw)?)
     }
 
     accepts!(CIDR);
@@ -16,19 +16,15 @@ impl<'a> FromSql<'a> for Ipv4Network {
 
 impl<'a> FromSql<'a> for Ipv6Network {
     fn from_sql(_: &Type, raw: &'a [u8]) -> PostgresResult<Ipv6Network> {
-        postgres_common::from_sql_ipv6_network(raw)
+        Ok(postgres_common::from_sql_ipv6_network(raw)?)
     }
 
     accepts!(CIDR);
 }
 
 impl<'a> FromSql<'a> for IpNetwork {
-    fn from_sql(t: &Type, raw: &'a [u8]) -> PostgresResult<IpNetwork> {
-        match raw[0] {
-            postgres_common::IPV4_TYPE => Ok(IpNetwork::V4(Ipv4Network::from_sql(t, raw)?)),
-            postgres_common::IPV6_TYPE => Ok(IpNetwork::V6(Ipv6Network::from_sql(t, raw)?)),
-            _ => Err("CIDR is not IP version 4 or 6".into()),
-        }
+    fn from_sql(_: &Type, raw: &'a [u8]) -> PostgresResult<IpNetwork> {
+        Ok(postgres_common::from_sql_ip_network(raw)?)
     }
 
     accepts!(CIDR);
@@ -0,0 +1,310 @@
+use std::collections::HashSet;
+use std::net::Ipv4Addr;
+use rand::Rng;
+use crate::Ipv4Network;
+
+impl Ipv4Network {
+    /// Returns up to `count` distinct random subnets with `prefix` netmask from this network,
+    /// sorted in ascending order. Subnets are sampled without replacement, so when network contains
+    /// less than `count` subnets with given prefix, all of them are returned.
+    ///
+    /// # Panics
+    ///
+    /// This method panics when prefix is bigger than 32 or when prefix is lower than netmask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16)?;
+    /// let subnets = ip_network.random_subnets(24, 10, &mut rand::thread_rng());
+    /// assert_eq!(10, subnets.len());
+    /// assert!(subnets.iter().all(|subnet| subnet.netmask() == 24));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn random_subnets<R: Rng + ?Sized>(
+        &self,
+        prefix: u8,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<Self> {
+        assert!(prefix <= Self::LENGTH);
+        assert!(prefix >= self.netmask);
+
+        let total = 1u64 << (prefix - self.netmask);
+        let count = (count as u64).min(total);
+
+        // Robert Floyd's sampling algorithm
+        let mut indexes = HashSet::with_capacity(count as usize);
+        for j in total - count..total {
+            let index = rng.gen_range(0..=j);
+            if !indexes.insert(index) {
+                indexes.insert(j);
+            }
+        }
+
+        let mut indexes = indexes.into_iter().collect::<Vec<_>>();
+        indexes.sort_unstable();
+
+        let network_address = u64::from(u32::from(self.network_address));
+        let step = 1u64 << (Self::LENGTH - prefix);
+        indexes
+            .into_iter()
+            .map(|index| Self {
+                network_address: Ipv4Addr::from((network_address + index * step) as u32),
+                netmask: prefix,
+            })
+            .collect()
+    }
+
+    /// Returns uniformly random IP address from this network that is not contained in any of
+    /// `exclusions` networks. Returns `None` when excluded networks cover whole network.
+    /// Exclusions can overlap each other and can be partially or fully outside this network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16)?;
+    /// let excluded = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 17)?;
+    /// let ip = ip_network.random_address_in_excluding(&[excluded], &mut rand::thread_rng()).unwrap();
+    /// assert!(ip_network.contains(ip));
+    /// assert!(!excluded.contains(ip));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn random_address_in_excluding<R: Rng + ?Sized>(
+        &self,
+        exclusions: &[Ipv4Network],
+        rng: &mut R,
+    ) -> Option<Ipv4Addr> {
+        let first = u64::from(u32::from(self.network_address));
+        let last = u64::from(u32::from(self.broadcast_address()));
+
+        // Excluded ranges inside this network, sorted and merged, so they don't overlap
+        let mut excluded = exclusions
+            .iter()
+            .map(|network| {
+                (
+                    u64::from(u32::from(network.network_address)).max(first),
+                    u64::from(u32::from(network.broadcast_address())).min(last),
+                )
+            })
+            .filter(|(from, to)| from <= to)
+            .collect::<Vec<_>>();
+        excluded.sort_unstable();
+
+        let mut merged: Vec<(u64, u64)> = Vec::with_capacity(excluded.len());
+        for (from, to) in excluded {
+            match merged.last_mut() {
+                Some(previous) if from <= previous.1 + 1 => previous.1 = previous.1.max(to),
+                _ => merged.push((from, to)),
+            }
+        }
+
+        let excluded_count: u64 = merged.iter().map(|(from, to)| to - from + 1).sum();
+        let remaining = last - first + 1 - excluded_count;
+        if remaining == 0 {
+            return None;
+        }
+
+        // Map random index from remaining addresses to address by skipping excluded ranges
+        let mut address = first + rng.gen_range(0..remaining);
+        for (from, to) in merged {
+            if from > address {
+                break;
+            }
+            address += to - from + 1;
+        }
+
+        Some(Ipv4Addr::from(address as u32))
+    }
+
+    /// Returns `parts` non overlapping subnets that together cover this network, sorted in
+    /// ascending order. Subnets are created by repeatedly splitting randomly chosen subnet to
+    /// halves, starting with this network. When network can't be split to `parts` subnets
+    /// (because all subnets already have netmask 32), less subnets are returned. When `parts` is
+    /// zero, empty vector is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use ip_network::Ipv4Network;
+    ///
+    /// let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16)?;
+    /// let subnets = ip_network.random_partition(10, &mut rand::thread_rng());
+    /// assert_eq!(10, subnets.len());
+    /// assert_eq!(vec![ip_network], Ipv4Network::collapse_addresses(&subnets));
+    /// # Ok::<(), ip_network::IpNetworkError>(())
+    /// ```
+    pub fn random_partition<R: Rng + ?Sized>(&self, parts: usize, rng: &mut R) -> Vec<Self> {
+        if parts == 0 {
+            return Vec::new();
+        }
+
+        let mut leaves = Vec::with_capacity(parts);
+        leaves.push(*self);
+        // Indexes to `leaves` of subnets that can be still split
+        let mut splittable = Vec::with_capacity(parts);
+        if self.netmask < Self::LENGTH {
+            splittable.push(0);
+        }
+
+        while leaves.len() < parts && !splittable.is_empty() {
+            let nth = rng.gen_range(0..splittable.len());
+            let index = splittable[nth];
+
+            let (first, second) = leaves[index].halves().unwrap();
+            leaves[index] = first;
+            leaves.push(second);
+
+            if first.netmask < Self::LENGTH {
+                splittable.push(leaves.len() - 1);
+            } else {
+                splittable.swap_remove(nth);
+            }
+        }
+
+        leaves.sort_unstable();
+        leaves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::net::Ipv4Addr;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::Ipv4Network;
+
+    #[test]
+    fn random_subnets() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        let subnets = ip_network.random_subnets(24, 100, &mut rng);
+
+        assert_eq!(100, subnets.len());
+        assert_eq!(100, subnets.iter().collect::<HashSet<_>>().len());
+        for subnet in subnets {
+            assert_eq!(24, subnet.netmask());
+            assert!(ip_network.contains(subnet.network_address()));
+        }
+    }
+
+    #[test]
+    fn random_subnets_count_bigger_than_space() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+        let subnets = ip_network.random_subnets(26, 10, &mut rng);
+
+        assert_eq!(
+            ip_network.subnets_with_prefix(26).collect::<Vec<_>>(),
+            subnets
+        );
+    }
+
+    #[test]
+    fn random_subnets_whole_range() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let subnets = Ipv4Network::DEFAULT_ROUTE.random_subnets(32, 5, &mut rng);
+        assert_eq!(5, subnets.len());
+    }
+
+    #[test]
+    fn random_address_in_excluding() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+        let exclusions = [
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 26).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 32), 27).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 128), 26).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 255), 32).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap(),
+        ];
+
+        let mut seen = HashSet::new();
+        for _ in 0..10_000 {
+            let ip = ip_network
+                .random_address_in_excluding(&exclusions, &mut rng)
+                .unwrap();
+            assert!(ip_network.contains(ip));
+            assert!(exclusions.iter().all(|excluded| !excluded.contains(ip)));
+            seen.insert(ip);
+        }
+        // 10.0.0.64 - 10.0.0.127 and 10.0.0.192 - 10.0.0.254
+        assert_eq!(64 + 63, seen.len());
+    }
+
+    #[test]
+    fn random_address_in_excluding_everything() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 24).unwrap();
+
+        let exclusions = [Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap()];
+        assert_eq!(
+            None,
+            ip_network.random_address_in_excluding(&exclusions, &mut rng)
+        );
+
+        let exclusions = [
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 25).unwrap(),
+            Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 128), 25).unwrap(),
+        ];
+        assert_eq!(
+            None,
+            ip_network.random_address_in_excluding(&exclusions, &mut rng)
+        );
+    }
+
+    #[test]
+    fn random_partition() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+        let subnets = ip_network.random_partition(50, &mut rng);
+
+        assert_eq!(50, subnets.len());
+        for subnet in &subnets {
+            assert!(ip_network.contains(subnet));
+        }
+        for (i, a) in subnets.iter().enumerate() {
+            for b in &subnets[i + 1..] {
+                assert!(!a.contains(b) && !b.contains(a));
+            }
+        }
+        let total: u64 = subnets
+            .iter()
+            .map(|subnet| 1u64 << (32 - subnet.netmask()))
+            .sum();
+        assert_eq!(1 << 24, total);
+    }
+
+    #[test]
+    fn random_partition_too_many_parts() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 30).unwrap();
+        assert_eq!(
+            ip_network.subnets_with_prefix(32).collect::<Vec<_>>(),
+            ip_network.random_partition(10, &mut rng)
+        );
+        assert_eq!(
+            Vec::<Ipv4Network>::new(),
+            ip_network.random_partition(0, &mut rng)
+        );
+        assert_eq!(vec![ip_network], ip_network.random_partition(1, &mut rng));
+    }
+
+    #[test]
+    fn random_address_in_excluding_whole_range() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let exclusions = [Ipv4Network::new(Ipv4Addr::new(0, 0, 0, 0), 1).unwrap()];
+        let ip = Ipv4Network::DEFAULT_ROUTE
+            .random_address_in_excluding(&exclusions, &mut rng)
+            .unwrap();
+        assert!(ip.octets()[0] >= 128);
+    }
+}
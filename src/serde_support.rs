@@ -10,7 +10,7 @@ impl Serialize for IpNetwork {
         S: Serializer,
     {
         if serializer.is_human_readable() {
-            serializer.serialize_str(&self.to_string())
+            serializer.collect_str(self)
         } else {
             match self {
                 IpNetwork::V4(a) => serializer.serialize_newtype_variant("IpNetwork", 0, "V4", a),
@@ -130,7 +130,7 @@ macro_rules! ser_de_impl {
                 S: Serializer,
             {
                 if serializer.is_human_readable() {
-                    serializer.serialize_str(&self.to_string())
+                    serializer.collect_str(self)
                 } else {
                     (self.network_address(), self.netmask()).serialize(serializer)
                 }
@@ -162,6 +162,8 @@ macro_rules! ser_de_impl {
 
                     deserializer.deserialize_str(IpNetworkVisitor)
                 } else {
+                    // Non canonical networks with host bits set are rejected, same as in
+                    // human readable format
                     let (network_address, netmask) = <(_, u8)>::deserialize(deserializer)?;
                     Self::new(network_address, netmask).map_err(Error::custom)
                 }
@@ -173,11 +175,195 @@ macro_rules! ser_de_impl {
 ser_de_impl!("IPv4 network" Ipv4Network);
 ser_de_impl!("IPv6 network" Ipv6Network);
 
+/// Alternative compact encoding of `Ipv4Network` as exactly five bytes (four bytes of network
+/// address followed by netmask), regardless of format being human readable or not.
+///
+/// Use it with `#[serde(with = "ip_network::serde_support::ipv4_network_bytes")]` attribute.
+/// For example in `bincode`, network is encoded to five bytes without any framing.
+///
+/// # Examples
+///
+/// ```
+/// use ip_network::Ipv4Network;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Route {
+///     #[serde(with = "ip_network::serde_support::ipv4_network_bytes")]
+///     network: Ipv4Network,
+/// }
+/// ```
+pub mod ipv4_network_bytes {
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, Serializer};
+    use crate::Ipv4Network;
+
+    /// Serializes `Ipv4Network` as five bytes.
+    pub fn serialize<S>(network: &Ipv4Network, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let [a, b, c, d] = network.octets();
+        [a, b, c, d, network.netmask()].serialize(serializer)
+    }
+
+    /// Deserializes `Ipv4Network` from five bytes. Networks with host bits set are rejected.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Ipv4Network, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let [a, b, c, d, netmask] = <[u8; 5]>::deserialize(deserializer)?;
+        Ipv4Network::new([a, b, c, d].into(), netmask).map_err(Error::custom)
+    }
+}
+
+/// Alternative human readable encoding of networks, that omits netmask for networks containing
+/// just one address (with netmask 32 for IPv4 or 128 for IPv6), so network `192.168.1.1/32` is
+/// serialized as `"192.168.1.1"`. Deserialization accepts both forms. Formats that are not human
+/// readable use the default encoding.
+///
+/// Use it with `#[serde(with = "ip_network::serde_ext::compact")]` attribute for
+/// `Ipv4Network`, `Ipv6Network` or `IpNetwork` field.
+///
+/// # Examples
+///
+/// ```
+/// use ip_network::Ipv4Network;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Host {
+///     #[serde(with = "ip_network::serde_ext::compact")]
+///     network: Ipv4Network,
+/// }
+/// ```
+pub mod compact {
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use std::str::FromStr;
+    use serde::de::{Deserialize, Deserializer, Error, Visitor};
+    use serde::ser::{Serialize, Serializer};
+    use crate::{IpNetwork, IpNetworkParseError, Ipv4Network, Ipv6Network};
+
+    mod private {
+        pub trait Sealed {}
+    }
+
+    /// Network types supported by [`compact`](index.html) module. This trait is sealed and cannot
+    /// be implemented outside of this crate.
+    pub trait CompactNetwork: Serialize + for<'de> Deserialize<'de> + private::Sealed {
+        #[doc(hidden)]
+        const EXPECTING: &'static str;
+
+        #[doc(hidden)]
+        fn fmt_compact(&self, fmt: &mut fmt::Formatter) -> fmt::Result;
+
+        #[doc(hidden)]
+        fn parse_compact(s: &str) -> Result<Self, IpNetworkParseError>;
+    }
+
+    macro_rules! compact_network_impl {
+        ($expecting:tt $ty:ty, $addr:ty) => {
+            impl private::Sealed for $ty {}
+
+            impl CompactNetwork for $ty {
+                const EXPECTING: &'static str = $expecting;
+
+                fn fmt_compact(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::Display::fmt(&self.display_host(), fmt)
+                }
+
+                fn parse_compact(s: &str) -> Result<Self, IpNetworkParseError> {
+                    if s.contains('/') {
+                        <$ty>::from_str(s)
+                    } else {
+                        <$addr>::from_str(s)
+                            .map(Self::from)
+                            .map_err(|_| IpNetworkParseError::AddrParseError)
+                    }
+                }
+            }
+        };
+    }
+
+    compact_network_impl!("IPv4 network or address" Ipv4Network, Ipv4Addr);
+    compact_network_impl!("IPv6 network or address" Ipv6Network, Ipv6Addr);
+    compact_network_impl!("IP network or address" IpNetwork, IpAddr);
+
+    struct Compact<'a, T>(&'a T);
+
+    impl<T: CompactNetwork> fmt::Display for Compact<'_, T> {
+        fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+            self.0.fmt_compact(fmt)
+        }
+    }
+
+    /// Serializes network without netmask when it contains just one address.
+    pub fn serialize<T, S>(network: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: CompactNetwork,
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&Compact(network))
+        } else {
+            network.serialize(serializer)
+        }
+    }
+
+    /// Deserializes network with or without netmask. Networks with host bits set are rejected.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: CompactNetwork,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            struct CompactVisitor<T>(PhantomData<T>);
+
+            impl<'de, T: CompactNetwork> Visitor<'de> for CompactVisitor<T> {
+                type Value = T;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str(T::EXPECTING)
+                }
+
+                fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+                where
+                    E: Error,
+                {
+                    T::parse_compact(s).map_err(Error::custom)
+                }
+            }
+
+            deserializer.deserialize_str(CompactVisitor(PhantomData))
+        } else {
+            T::deserialize(deserializer)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{IpNetwork, Ipv4Network, Ipv6Network};
-    use serde_test::{assert_tokens, Configure, Token};
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Configure,
+        Token,
+    };
     use std::net::{Ipv4Addr, Ipv6Addr};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Ipv4NetworkBytes(#[serde(with = "super::ipv4_network_bytes")] Ipv4Network);
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct CompactIpv4Network(#[serde(with = "crate::serde_ext::compact")] Ipv4Network);
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct CompactIpv6Network(#[serde(with = "crate::serde_ext::compact")] Ipv6Network);
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct CompactIpNetwork(#[serde(with = "crate::serde_ext::compact")] IpNetwork);
 
     #[test]
     fn ip_network_serialize_readable() {
@@ -186,6 +372,14 @@ mod tests {
         assert_tokens(&ip_network.readable(), &[Token::BorrowedStr("1.2.3.4/32")]);
     }
 
+    #[test]
+    fn ip_network_serialize_readable_output() {
+        let ip_network =
+            IpNetwork::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+
+        assert_ser_tokens(&ip_network.readable(), &[Token::Str("2001:db8::/32")]);
+    }
+
     #[test]
     fn ip_network_serialize_compact() {
         let ip_network = IpNetwork::new(Ipv4Addr::new(1, 2, 3, 4), 32).unwrap();
@@ -217,6 +411,13 @@ mod tests {
         assert_tokens(&ip_network.readable(), &[Token::BorrowedStr("1.2.3.4/32")]);
     }
 
+    #[test]
+    fn ipv4_network_serialize_readable_output() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+
+        assert_ser_tokens(&ip_network.readable(), &[Token::Str("192.168.0.0/16")]);
+    }
+
     #[test]
     fn ipv4_network_serialize_compact() {
         let ip_network = Ipv4Network::new(Ipv4Addr::new(1, 2, 3, 4), 32).unwrap();
@@ -237,6 +438,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ipv4_network_deserialize_compact_host_bits_set() {
+        assert_de_tokens_error::<serde_test::Compact<Ipv4Network>>(
+            &[
+                Token::Tuple { len: 2 },
+                Token::Tuple { len: 4 },
+                Token::U8(192),
+                Token::U8(168),
+                Token::U8(1),
+                Token::U8(1),
+                Token::TupleEnd,
+                Token::U8(24),
+                Token::TupleEnd,
+            ],
+            "IP network address has host bits set",
+        );
+    }
+
+    #[test]
+    fn ipv4_network_deserialize_readable_host_bits_set() {
+        assert_de_tokens_error::<serde_test::Readable<Ipv4Network>>(
+            &[Token::Str("192.168.1.1/24")],
+            "IP network address has host bits set",
+        );
+    }
+
     #[test]
     fn ipv6_network_serialize_readable() {
         let ip_network = Ipv6Network::new(Ipv6Addr::new(1, 2, 3, 4, 0, 0, 0, 0), 64).unwrap();
@@ -247,6 +474,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ipv6_network_serialize_readable_output() {
+        let ip_network =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+
+        assert_ser_tokens(&ip_network.readable(), &[Token::Str("2001:db8::/32")]);
+    }
+
     #[test]
     fn ipv6_network_serialize_compact() {
         let ip_network = Ipv6Network::new(Ipv6Addr::new(1, 2, 3, 4, 0, 0, 0, 0), 64).unwrap();
@@ -278,4 +513,152 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn ipv4_network_bytes_tokens() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+
+        assert_tokens(
+            &Ipv4NetworkBytes(ip_network),
+            &[
+                Token::NewtypeStruct {
+                    name: "Ipv4NetworkBytes",
+                },
+                Token::Tuple { len: 5 },
+                Token::U8(192),
+                Token::U8(168),
+                Token::U8(1),
+                Token::U8(0),
+                Token::U8(24),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn ipv4_network_bytes_bincode() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 1, 0), 24).unwrap();
+
+        let encoded = bincode::serialize(&Ipv4NetworkBytes(ip_network)).unwrap();
+        assert_eq!(encoded, vec![192, 168, 1, 0, 24]);
+        assert_eq!(
+            5,
+            bincode::serialized_size(&Ipv4NetworkBytes(ip_network)).unwrap()
+        );
+
+        let decoded: Ipv4NetworkBytes = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(Ipv4NetworkBytes(ip_network), decoded);
+    }
+
+    #[test]
+    fn ipv4_network_bytes_host_bits_set() {
+        let result = bincode::deserialize::<Ipv4NetworkBytes>(&[192, 168, 1, 1, 24]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn compact_ipv4_network_host() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(1, 2, 3, 4), 32).unwrap();
+
+        assert_tokens(
+            &CompactIpv4Network(ip_network).readable(),
+            &[
+                Token::NewtypeStruct {
+                    name: "CompactIpv4Network",
+                },
+                Token::Str("1.2.3.4"),
+            ],
+        );
+        assert_de_tokens(
+            &CompactIpv4Network(ip_network).readable(),
+            &[
+                Token::NewtypeStruct {
+                    name: "CompactIpv4Network",
+                },
+                Token::Str("1.2.3.4/32"),
+            ],
+        );
+    }
+
+    #[test]
+    fn compact_ipv4_network() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+
+        assert_tokens(
+            &CompactIpv4Network(ip_network).readable(),
+            &[
+                Token::NewtypeStruct {
+                    name: "CompactIpv4Network",
+                },
+                Token::Str("192.168.0.0/16"),
+            ],
+        );
+    }
+
+    #[test]
+    fn compact_ipv4_network_not_readable() {
+        let ip_network = Ipv4Network::new(Ipv4Addr::new(1, 2, 3, 4), 32).unwrap();
+
+        assert_tokens(
+            &CompactIpv4Network(ip_network).compact(),
+            &[
+                Token::NewtypeStruct {
+                    name: "CompactIpv4Network",
+                },
+                Token::Tuple { len: 2 },
+                Token::Tuple { len: 4 },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::U8(4),
+                Token::TupleEnd,
+                Token::U8(32),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn compact_ipv6_network_host() {
+        let ip_network =
+            Ipv6Network::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 128).unwrap();
+
+        assert_tokens(
+            &CompactIpv6Network(ip_network).readable(),
+            &[
+                Token::NewtypeStruct {
+                    name: "CompactIpv6Network",
+                },
+                Token::Str("2001:db8::1"),
+            ],
+        );
+    }
+
+    #[test]
+    fn compact_ip_network_host() {
+        let ip_network = IpNetwork::new(Ipv4Addr::new(1, 2, 3, 4), 32).unwrap();
+
+        assert_tokens(
+            &CompactIpNetwork(ip_network).readable(),
+            &[
+                Token::NewtypeStruct {
+                    name: "CompactIpNetwork",
+                },
+                Token::Str("1.2.3.4"),
+            ],
+        );
+    }
+
+    #[test]
+    fn compact_ipv4_network_invalid() {
+        assert_de_tokens_error::<serde_test::Readable<CompactIpv4Network>>(
+            &[
+                Token::NewtypeStruct {
+                    name: "CompactIpv4Network",
+                },
+                Token::Str("1.2.3"),
+            ],
+            "invalid IP address syntax",
+        );
+    }
 }